@@ -0,0 +1,27 @@
+use aoclib::Day;
+
+/// All implemented days in order.
+pub const DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day3::Day3>(),
+    Day::of::<day4::Day4>(),
+    Day::of::<day5::Day5>(),
+    Day::of::<day6::Day6>(),
+    Day::of::<day7::Day7>(),
+    Day::of::<day8::Day8>(),
+    Day::of::<day9::Day9>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+    Day::of::<day14::Day14>(),
+    Day::of::<day15::Day15>(),
+    Day::of::<day16::Day16>(),
+    Day::of::<day17::Day17>(),
+    Day::of::<day18::Day18>(),
+    Day::of::<day19::Day19>(),
+    Day::of::<day20::Day20>(),
+    Day::of::<day22::Day22>(),
+    Day::of::<day23::Day23>(),
+];
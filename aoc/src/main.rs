@@ -0,0 +1,631 @@
+use std::{
+    array,
+    collections::BTreeSet,
+    env, fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoclib::{
+    bench::{Bench, Time},
+    fetch::{self, Client},
+    read_input,
+    record::{self, Record},
+    Answer, Day, Error, KnownAnswers, Verdict,
+};
+
+mod days;
+mod new;
+mod submit;
+
+/// The year of the puzzles.
+const YEAR: u16 = 2024;
+
+const USAGE: &str = "usage: aoc run [--bench] [--iterations N] [--viz] [--input FILE] [--part PART]
+               [--param KEY=VALUE]... [-v] [DAYS]
+       aoc fetch DAY
+       aoc submit DAY PART
+       aoc new DAY
+
+commands:
+    run      solve the selected days and verify their answers
+    fetch    download the input of DAY to dayN/input using the session in ~/.aocrc,
+             the server can be changed with the AOC_URL environment variable
+    submit   submit the answer to PART of DAY from dayN/output, unless it was
+             guessed wrong before, and record it in answers.toml if correct
+    new      create the crate of DAY from template.rs and register it in the runner,
+             existing files are kept
+
+DAYS is a comma separated list of days or ranges like `5`, `1..=23` or `3..`.
+Runs all implemented days if omitted.
+
+options:
+    --bench           time parsing, part 1 and part 2 instead of writing answers
+    --iterations N    number of timed runs per stage when benchmarking
+    --viz             let days draw what they do, to the terminal and into viz/
+    --input FILE      read the input of a single day from FILE, or stdin for `-`,
+                      the answers are neither written nor verified
+    --part PART       only solve part 1 or 2
+    --param KEY=VALUE set a puzzle parameter like a grid size, can be repeated
+    -v, --verbose     print debug output of the days";
+
+/// A parsed command line.
+enum Command {
+    Run(Run),
+    Fetch { day: u8 },
+    Submit { day: u8, part: u8 },
+    New { day: u8 },
+}
+
+fn main() -> ExitCode {
+    match parse_args(env::args().skip(1)) {
+        Ok(Command::Run(options)) => {
+            aoclib::log::set_verbose(options.verbose);
+            for (key, value) in &options.params {
+                aoclib::params::set(key, value);
+            }
+            if options.viz {
+                aoclib::viz::enable(workspace().join("viz"));
+            }
+            match options.bench {
+                Some(config) => bench(&options, config),
+                None => run(&options),
+            }
+        }
+        Ok(Command::Fetch { day }) => fetch(day),
+        Ok(Command::Submit { day, part }) => submit::submit(day, part),
+        Ok(Command::New { day }) => new::new(day),
+        Err(msg) => {
+            if !msg.is_empty() {
+                eprintln!("error: {msg}");
+            }
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The options of `aoc run`.
+struct Run {
+    days: BTreeSet<u8>,
+    bench: Option<Bench>,
+    viz: bool,
+    /// Read instead of `dayN/input`, `-` is stdin.
+    input: Option<String>,
+    part: Option<u8>,
+    params: Vec<(String, String)>,
+    verbose: bool,
+}
+
+/// Parses the command line arguments following the program name.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => parse_run(args),
+        Some("fetch") => match (args.next(), args.next()) {
+            (Some(day), None) => Ok(Command::Fetch {
+                day: parse_day(&day)?,
+            }),
+            _ => Err("fetch needs exactly one day".to_string()),
+        },
+        Some("submit") => match (args.next(), args.next(), args.next()) {
+            (Some(day), Some(part), None) => Ok(Command::Submit {
+                day: parse_day(&day)?,
+                part: parse_part(&part)?,
+            }),
+            _ => Err("submit needs a day and a part".to_string()),
+        },
+        Some("new") => match (args.next(), args.next()) {
+            (Some(day), None) => Ok(Command::New {
+                day: parse_day(&day)?,
+            }),
+            _ => Err("new needs exactly one day".to_string()),
+        },
+        _ => Err(String::new()),
+    }
+}
+
+/// Parses the arguments of `aoc run`.
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut days = None;
+    let mut bench = None;
+    let mut iterations = None;
+    let mut viz = false;
+    let mut input = None;
+    let mut part = None;
+    let mut params = Vec::new();
+    let mut verbose = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bench" => bench = Some(Bench::default()),
+            "--viz" => viz = true,
+            "-v" | "--verbose" => verbose = true,
+            "--input" if input.is_some() => return Err("--input given twice".to_string()),
+            "--input" => input = Some(args.next().ok_or("--input needs a file")?),
+            "--part" if part.is_some() => return Err("--part given twice".to_string()),
+            "--part" => part = Some(parse_part(&args.next().ok_or("--part needs a value")?)?),
+            "--param" => {
+                let pair = args.next().ok_or("--param needs a value")?;
+                let (key, value) = aoclib::params::parse_pair(&pair)
+                    .ok_or_else(|| format!("invalid parameter `{pair}`, expected KEY=VALUE"))?;
+                params.push((key.to_string(), value.to_string()));
+            }
+            "--iterations" => {
+                let n = args.next().ok_or("--iterations needs a value")?;
+                let n = n
+                    .parse()
+                    .map_err(|_| format!("invalid iteration count `{n}`"))?;
+                iterations = Some(n);
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option `{flag}`")),
+            spec if days.is_none() => days = Some(select(spec)?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    match (&mut bench, iterations) {
+        (Some(bench), Some(n)) => bench.iterations = n,
+        (None, Some(_)) => return Err("--iterations only applies to --bench".to_string()),
+        _ => {}
+    }
+    if viz && bench.is_some() {
+        return Err("--viz would draw every benchmark run, use it without --bench".to_string());
+    }
+    if part.is_some() && bench.is_some() {
+        return Err("--bench times both parts, use --part without it".to_string());
+    }
+    if input.is_some() && days.as_ref().is_none_or(|days| days.len() != 1) {
+        return Err("--input needs exactly one day".to_string());
+    }
+    Ok(Command::Run(Run {
+        days: days.unwrap_or_else(|| days::DAYS.iter().map(|d| d.day).collect()),
+        bench,
+        viz,
+        input,
+        part,
+        params,
+        verbose,
+    }))
+}
+
+/// Parses a single puzzle day.
+fn parse_day(s: &str) -> Result<u8, String> {
+    s.parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("invalid day `{s}`"))
+}
+
+/// Parses a part of a puzzle.
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part `{s}`")),
+    }
+}
+
+/// Parses a day selection like `1,3..=5,20..`.
+///
+/// Ranges skip days that are not implemented, but fail if that leaves none.
+fn select(spec: &str) -> Result<BTreeSet<u8>, String> {
+    let implemented = |day: &u8| days::DAYS.iter().any(|d| d.day == *day);
+    let parse = |s: &str, default: u8| -> Result<u8, String> {
+        if s.is_empty() {
+            return Ok(default);
+        }
+        s.parse().map_err(|_| format!("invalid day `{s}`"))
+    };
+
+    let mut selection = BTreeSet::new();
+    for part in spec.split(',') {
+        if let Some((from, to)) = part.split_once("..") {
+            let from = parse(from, 1)?;
+            let to = match to.strip_prefix('=') {
+                Some(to) => parse(to, 25)?,
+                None if to.is_empty() => 25,
+                None => parse(to, 25)?.saturating_sub(1),
+            };
+            if !(from..=to).any(|d| implemented(&d)) {
+                return Err(format!("no implemented day in `{part}`"));
+            }
+            selection.extend((from..=to).filter(implemented));
+        } else {
+            let day = parse(part, 0)?;
+            if !implemented(&day) {
+                return Err(format!("day {day} is not implemented"));
+            }
+            selection.insert(day);
+        }
+    }
+    Ok(selection)
+}
+
+/// Returns the root directory of the workspace.
+fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("The runner lives inside the workspace.")
+}
+
+/// Returns the directory of `day` inside the workspace.
+fn day_dir(day: u8) -> PathBuf {
+    workspace().join(format!("day{day}"))
+}
+
+/// Returns the path of the known answers of all days.
+fn answers_file() -> PathBuf {
+    workspace().join("answers.toml")
+}
+
+/// Creates a client for [`YEAR`] talking to `AOC_URL` or adventofcode.com.
+fn client() -> aoclib::Result<Client> {
+    let base_url = env::var("AOC_URL").unwrap_or_else(|_| fetch::BASE_URL.to_string());
+    Client::from_session_file(base_url, YEAR)
+}
+
+/// Result of running a single day.
+enum Outcome<T> {
+    Done(T),
+    Failed(Error, String),
+    Panicked,
+}
+
+/// Reads the input of `day` from `dayN/input`, or from `source` if given.
+fn day_input(day: u8, source: Option<&str>) -> aoclib::Result<String> {
+    match source {
+        None => read_input(day_dir(day).join("input")),
+        Some("-") => Ok(io::read_to_string(io::stdin())?),
+        Some(path) => read_input(path),
+    }
+}
+
+/// Reads the input of `day` and runs `f` on it.
+fn run_day<T>(
+    day: u8,
+    source: Option<&str>,
+    f: impl FnOnce(&str) -> aoclib::Result<T>,
+) -> Outcome<T> {
+    let input = match day_input(day, source) {
+        Ok(input) => input,
+        Err(e) => return Outcome::Failed(e, String::new()),
+    };
+
+    // a panicking day should not take down the others
+    match panic::catch_unwind(AssertUnwindSafe(|| f(&input))) {
+        Ok(Ok(done)) => Outcome::Done(done),
+        Ok(Err(e)) => Outcome::Failed(e, input),
+        Err(_) => Outcome::Panicked,
+    }
+}
+
+/// Runs `f` for all selected days, prints a table with a row of `cells` per day
+/// and reports failures.
+fn run_all<T, const N: usize>(
+    options: &Run,
+    header: &[&str; N],
+    f: impl Fn(&Day, &str) -> aoclib::Result<T>,
+    cells: impl Fn(&T) -> [String; N],
+) -> Vec<Outcome<T>> {
+    let outcomes: Vec<(u8, Outcome<T>)> = days::DAYS
+        .iter()
+        .filter(|d| options.days.contains(&d.day))
+        .map(|d| {
+            let outcome = run_day(d.day, options.input.as_deref(), |input| f(d, input));
+            (d.day, outcome)
+        })
+        .collect();
+
+    let rows: Vec<[String; N]> = outcomes
+        .iter()
+        .map(|(day, outcome)| {
+            let msg = match outcome {
+                Outcome::Done(done) => return cells(done),
+                Outcome::Failed(e, _) => format!("error: {e}"),
+                Outcome::Panicked => "panicked".to_string(),
+            };
+            array::from_fn(|i| match i {
+                0 => day.to_string(),
+                1 => msg.clone(),
+                _ => String::new(),
+            })
+        })
+        .collect();
+    print_table(header, &rows);
+
+    for (_, outcome) in &outcomes {
+        if let Outcome::Failed(e, input) = outcome {
+            eprintln!("\n{}", e.report(input));
+        }
+    }
+    outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+/// Returns the exit code for a run that `succeeded` or not.
+fn exit_code(succeeded: bool) -> ExitCode {
+    if succeeded {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Writes `answers` to the output file of `day`, keeping the lines of parts
+/// that were not solved.
+fn write_output(day: u8, answers: &[Option<Answer>; 2]) -> aoclib::Result<()> {
+    let path = day_dir(day).join("output");
+    let old = fs::read_to_string(&path).unwrap_or_default();
+    let mut old = old.lines();
+    let [p1, p2] = answers.each_ref().map(|answer| {
+        let old = old.next().unwrap_or_default();
+        answer.as_ref().map_or(old, |a| &a.value)
+    });
+    fs::write(path, format!("{p1}\n{p2}\n"))?;
+    Ok(())
+}
+
+/// Appends a record of every answer to `dayN/output.jsonl`.
+fn write_records(day: u8, input: &str, answers: &[Option<Answer>; 2]) -> aoclib::Result<()> {
+    let input_hash = record::hash_input(input);
+    let records: Vec<Record> = (1..)
+        .zip(answers)
+        .filter_map(|(part, answer)| {
+            let answer = answer.as_ref()?;
+            Some(Record {
+                day,
+                part,
+                answer: answer.value.clone(),
+                duration: answer.time,
+                input_hash,
+            })
+        })
+        .collect();
+    record::append(day_dir(day).join("output.jsonl"), &records)
+}
+
+/// Solves all selected days, writes the answers to their output files and
+/// verifies them against the known answers.
+///
+/// Answers to an input given with `--input` are only printed and recorded
+/// in `dayN/output.jsonl`.
+fn run(options: &Run) -> ExitCode {
+    let path = answers_file();
+    let known = match KnownAnswers::load(&path) {
+        Ok(known) => known,
+        Err(e) => {
+            let text = fs::read_to_string(&path).unwrap_or_default();
+            eprintln!("{}", e.report(&text));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let outcomes = run_all(
+        options,
+        &["Day", "Part 1", "Part 2"],
+        |day, input| {
+            let answers = match options.part {
+                Some(part) => {
+                    let mut answers = [None, None];
+                    answers[part as usize - 1] = Some((day.solve_part)(input, part)?);
+                    answers
+                }
+                None => {
+                    let answers = (day.solve)(input)?;
+                    [Some(answers.part1), Some(answers.part2)]
+                }
+            };
+            write_records(day.day, input, &answers)?;
+            if options.input.is_some() {
+                return Ok((day.day, answers, [None, None]));
+            }
+            write_output(day.day, &answers)?;
+            let mut verdicts = [None, None];
+            for (part, (answer, verdict)) in (1..).zip(answers.iter().zip(&mut verdicts)) {
+                if let Some(answer) = answer {
+                    *verdict = Some(known.check(day.day, part, &answer.value)?);
+                }
+            }
+            Ok((day.day, answers, verdicts))
+        },
+        |(day, answers, verdicts)| {
+            let [p1, p2] = array::from_fn(|i| match (&answers[i], &verdicts[i]) {
+                (Some(answer), Some(verdict)) => format!("{answer} {verdict}"),
+                (Some(answer), None) => answer.to_string(),
+                (None, _) => "-".to_string(),
+            });
+            [day.to_string(), p1, p2]
+        },
+    );
+
+    let mut succeeded = true;
+    for outcome in &outcomes {
+        let (day, verdicts) = match outcome {
+            Outcome::Done((day, _, verdicts)) => (day, verdicts),
+            _ => {
+                succeeded = false;
+                continue;
+            }
+        };
+        for (part, verdict) in (1..).zip(verdicts) {
+            if let Some(Verdict::Wrong(expected)) = verdict {
+                eprintln!("error: wrong answer for day {day} part {part}, expected {expected}");
+                succeeded = false;
+            }
+        }
+    }
+    exit_code(succeeded)
+}
+
+/// Benchmarks all selected days. Every cell shows min / median / mean.
+fn bench(options: &Run, config: Bench) -> ExitCode {
+    let outcomes = run_all(
+        options,
+        &["Day", "Parse", "Part 1", "Part 2", "Total"],
+        |day, input| Ok((day.day, (day.bench)(input, config)?)),
+        |(day, t)| {
+            [
+                day.to_string(),
+                t.parse.to_string(),
+                t.part1.to_string(),
+                t.part2.to_string(),
+                Time(t.total()).to_string(),
+            ]
+        },
+    );
+    exit_code(outcomes.iter().all(|o| matches!(o, Outcome::Done(_))))
+}
+
+/// Downloads the input of `day`, refusing to overwrite an existing one.
+fn fetch(day: u8) -> ExitCode {
+    let path = day_dir(day).join("input");
+    match client().and_then(|c| c.save_input(day, &path)) {
+        Ok(input) => {
+            println!(
+                "downloaded {} lines to {}",
+                input.lines().count(),
+                path.display()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints `rows` as a table with a header. The first column is right aligned.
+fn print_table<const N: usize>(header: &[&str; N], rows: &[[String; N]]) {
+    let mut widths = header.map(|h| h.len());
+    for row in rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: [&str; N]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(i, (c, w))| match i {
+                0 => format!("{c:>w$}"),
+                _ => format!("{c:<w$}"),
+            })
+            .collect();
+        println!("{}", cells.join(" | ").trim_end());
+    };
+
+    line(*header);
+    println!("{}", widths.map(|w| "-".repeat(w)).join("-+-"));
+    for row in rows {
+        line(row.each_ref().map(String::as_str));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses `aoc run` with the whitespace separated `args`.
+    fn run_args(args: &str) -> Result<Run, String> {
+        match parse_run(args.split_whitespace().map(String::from))? {
+            Command::Run(run) => Ok(run),
+            _ => unreachable!(),
+        }
+    }
+
+    fn days(spec: &str) -> Result<Vec<u8>, String> {
+        select(spec).map(|days| days.into_iter().collect())
+    }
+
+    #[test]
+    fn select_days() {
+        assert_eq!(days("5"), Ok(vec![5]));
+        assert_eq!(days("3..6"), Ok(vec![3, 4, 5]));
+        assert_eq!(days("3..=6"), Ok(vec![3, 4, 5, 6]));
+        // day 21 is not implemented
+        assert_eq!(days("19..=22"), Ok(vec![19, 20, 22]));
+        assert_eq!(days("22.."), Ok(vec![22, 23]));
+        assert_eq!(days("..3,7,2"), Ok(vec![1, 2, 7]));
+    }
+
+    #[test]
+    fn select_errors() {
+        assert_eq!(days("21"), Err("day 21 is not implemented".to_string()));
+        assert_eq!(days("0"), Err("day 0 is not implemented".to_string()));
+        assert_eq!(
+            days("30.."),
+            Err("no implemented day in `30..`".to_string())
+        );
+        assert_eq!(
+            days("21..=21"),
+            Err("no implemented day in `21..=21`".to_string())
+        );
+        assert_eq!(
+            days("6..3"),
+            Err("no implemented day in `6..3`".to_string())
+        );
+        assert_eq!(days("x"), Err("invalid day `x`".to_string()));
+        assert_eq!(days("1..y"), Err("invalid day `y`".to_string()));
+        assert_eq!(days("1,,2"), Err("day 0 is not implemented".to_string()));
+    }
+
+    #[test]
+    fn options() {
+        let run = run_args("--input - --part 2 --param dim=6 --param bytes=12 -v 18").unwrap();
+        assert_eq!(run.input.as_deref(), Some("-"));
+        assert_eq!(run.days, BTreeSet::from([18]));
+        assert_eq!(run.part, Some(2));
+        assert_eq!(
+            run.params,
+            [("dim", "6"), ("bytes", "12")].map(|(k, v)| (k.to_string(), v.to_string()))
+        );
+        assert!(run.verbose);
+        assert!(!run_args("5").unwrap().verbose);
+        assert_eq!(
+            run_args("--input file 3").unwrap().input.as_deref(),
+            Some("file")
+        );
+        let bench = run_args("--iterations 5 --bench").unwrap().bench;
+        assert_eq!(bench.map(|b| b.iterations), Some(5));
+    }
+
+    #[test]
+    fn option_errors() {
+        for (args, msg) in [
+            ("--param dim", "invalid parameter `dim`, expected KEY=VALUE"),
+            ("--param =6", "invalid parameter `=6`, expected KEY=VALUE"),
+            ("--param", "--param needs a value"),
+            ("--part 1 --part 2", "--part given twice"),
+            ("--part 3", "invalid part `3`"),
+            ("--part", "--part needs a value"),
+            ("--input -", "--input needs exactly one day"),
+            ("--input - 1..3", "--input needs exactly one day"),
+            ("--input", "--input needs a file"),
+            ("--input a --input b 1", "--input given twice"),
+            ("--iterations 5 3", "--iterations only applies to --bench"),
+            ("--bench --iterations x", "invalid iteration count `x`"),
+            (
+                "--bench --part 1",
+                "--bench times both parts, use --part without it",
+            ),
+            ("--quiet", "unknown option `--quiet`"),
+        ] {
+            assert_eq!(run_args(args).err().as_deref(), Some(msg), "{args}");
+        }
+    }
+
+    #[test]
+    fn parse_days() {
+        assert_eq!(parse_day("25"), Ok(25));
+        assert_eq!(parse_day("26"), Err("invalid day `26`".to_string()));
+        assert_eq!(parse_day("-1"), Err("invalid day `-1`".to_string()));
+        assert_eq!(run_args("").unwrap().days.len(), days::DAYS.len());
+        assert_eq!(run_args("1..3").unwrap().days, BTreeSet::from([1, 2]));
+        assert_eq!(
+            run_args("1 2").err(),
+            Some("unexpected argument `2`".to_string())
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::Path,
+    process::ExitCode,
+};
+
+use aoclib::Result;
+
+use crate::day_dir;
+
+const TEMPLATE: &str = include_str!("../../template.rs");
+
+const EXAMPLES: &str = "# expected answers of the example files, checked by `cargo test`
+[example]
+# part1 = \"\"
+# part2 = \"\"
+";
+
+/// Creates the crate of `day` and registers it in the runner.
+///
+/// Existing files are never overwritten, so running it again only adds what
+/// is missing.
+pub fn new(day: u8) -> ExitCode {
+    match scaffold(day) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn scaffold(day: u8) -> Result<()> {
+    scaffold_in(&day_dir(day), Path::new(env!("CARGO_MANIFEST_DIR")), day)
+}
+
+/// Creates the crate of `day` in `dir` and registers it in the runner crate
+/// at `runner`.
+fn scaffold_in(dir: &Path, runner: &Path, day: u8) -> Result<()> {
+    fs::create_dir_all(dir.join("src"))?;
+
+    let manifest = format!(
+        "[package]\nname = \"day{day}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n\
+         [dependencies]\naoclib = {{ path = '../aoclib' }}\n"
+    );
+    create(&dir.join("Cargo.toml"), &manifest)?;
+    create(
+        &dir.join("src/lib.rs"),
+        &TEMPLATE.replace("XX", &day.to_string()),
+    )?;
+    create(&dir.join("example"), "")?;
+    create(&dir.join("examples.toml"), EXAMPLES)?;
+
+    // [dependencies] is the last table, so new days go at the end
+    register(
+        &runner.join("Cargo.toml"),
+        day,
+        &format!("day{day} = {{ path = '../day{day}' }}"),
+        manifest_day,
+        |_| false,
+    )?;
+    register(
+        &runner.join("src/days.rs"),
+        day,
+        &format!("    Day::of::<day{day}::Day{day}>(),"),
+        runner_day,
+        |line| line.trim() == "];",
+    )?;
+    Ok(())
+}
+
+/// Returns the day a dependency line of the runner manifest names.
+fn manifest_day(line: &str) -> Option<u8> {
+    let (name, _) = line.split_once(" = ")?;
+    name.strip_prefix("day")?.parse().ok()
+}
+
+/// Returns the day an entry of `DAYS` names.
+fn runner_day(line: &str) -> Option<u8> {
+    let name = line.trim().strip_prefix("Day::of::<day")?;
+    name.split_once("::")?.0.parse().ok()
+}
+
+/// Creates `path` with `contents` unless it exists already.
+fn create(path: &Path, contents: &str) -> Result<()> {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(mut file) => {
+            file.write_all(contents.as_bytes())?;
+            println!("created {}", path.display());
+        }
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+            println!("kept existing {}", path.display());
+        }
+        Err(e) => return Err(e.into()),
+    }
+    Ok(())
+}
+
+/// Inserts `entry` into the sorted list of days in the file at `path`.
+///
+/// `day_of` returns the day a line registers, if any. The entry is placed
+/// after the last line registering an earlier day. Without any days it goes
+/// before the first line `ends_list` accepts, or at the end of the file.
+fn register(
+    path: &Path,
+    day: u8,
+    entry: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+    ends_list: impl Fn(&str) -> bool,
+) -> Result<()> {
+    let text = fs::read_to_string(path)?;
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.iter().any(|l| day_of(l) == Some(day)) {
+        println!("day {day} is registered in {} already", path.display());
+        return Ok(());
+    }
+
+    let days: Vec<(usize, u8)> = (0..lines.len())
+        .filter_map(|i| Some((i, day_of(lines[i])?)))
+        .collect();
+    let at = match days.iter().rev().find(|&&(_, d)| d < day) {
+        Some(&(i, _)) => i + 1,
+        // before the first day, or where the empty list ends
+        None => match days.first() {
+            Some(&(i, _)) => i,
+            None => lines
+                .iter()
+                .position(|l| ends_list(l))
+                .unwrap_or(lines.len()),
+        },
+    };
+    lines.insert(at, entry);
+
+    fs::write(path, lines.join("\n") + "\n")?;
+    println!("registered day {day} in {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    /// Returns an empty directory for the test `name`.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-new-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).expect("Could not create temp dir.");
+        dir
+    }
+
+    /// Registers `day` in a `days.rs` holding `text` and returns the result.
+    fn register_in(name: &str, text: &str, day: u8) -> String {
+        let dir = temp_dir(name);
+        let path = dir.join("days.rs");
+        fs::write(&path, text).unwrap();
+        let entry = format!("    Day::of::<day{day}::Day{day}>(),");
+        register(&path, day, &entry, runner_day, |l| l.trim() == "];").unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_dir_all(dir);
+        text
+    }
+
+    const HEAD: &str = "pub const DAYS: &[Day] = &[\n";
+
+    #[test]
+    fn register_empty() {
+        let text = format!("{HEAD}];\n");
+        assert_eq!(
+            register_in("empty", &text, 3),
+            format!("{HEAD}    Day::of::<day3::Day3>(),\n];\n")
+        );
+    }
+
+    #[test]
+    fn register_sorted() {
+        let one = "    Day::of::<day1::Day1>(),\n";
+        let two = "    Day::of::<day2::Day2>(),\n";
+        let five = "    Day::of::<day5::Day5>(),\n";
+        let text = format!("{HEAD}{two}{five}];\n");
+        assert_eq!(
+            register_in("middle", &text, 3),
+            format!("{HEAD}{two}    Day::of::<day3::Day3>(),\n{five}];\n")
+        );
+        assert_eq!(
+            register_in("first", &text, 1),
+            format!("{HEAD}{one}{two}{five}];\n")
+        );
+    }
+
+    #[test]
+    fn register_twice() {
+        let text = format!("{HEAD}    Day::of::<day2::Day2>(),\n];\n");
+        assert_eq!(register_in("twice", &text, 2), text);
+    }
+
+    #[test]
+    fn scaffold_keeps_existing_work() {
+        let root = temp_dir("scaffold");
+        let (dir, runner) = (root.join("day7"), root.join("aoc"));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::create_dir_all(runner.join("src")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "// my solution\n").unwrap();
+        fs::write(runner.join("Cargo.toml"), "[dependencies]\n").unwrap();
+        fs::write(runner.join("src/days.rs"), format!("{HEAD}];\n")).unwrap();
+
+        scaffold_in(&dir, &runner, 7).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("src/lib.rs")).unwrap(),
+            "// my solution\n"
+        );
+        assert!(dir.join("examples.toml").exists());
+        assert_eq!(
+            fs::read_to_string(runner.join("Cargo.toml")).unwrap(),
+            "[dependencies]\nday7 = { path = '../day7' }\n"
+        );
+        let _ = fs::remove_dir_all(root);
+    }
+}
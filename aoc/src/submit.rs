@@ -0,0 +1,81 @@
+use std::{fs, process::ExitCode, thread};
+
+use aoclib::{
+    submit::{Guesses, Reply},
+    KnownAnswers, Result,
+};
+
+use crate::{answers_file, client, day_dir};
+
+/// How often a rate limited answer is retried.
+const RETRIES: usize = 3;
+
+/// Submits the answer to `part` of `day` found in its output file.
+pub fn submit(day: u8, part: u8) -> ExitCode {
+    match try_submit(day, part) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Returns true if the part is solved after submitting.
+fn try_submit(day: u8, part: u8) -> Result<bool> {
+    let output = day_dir(day).join("output");
+    let Some(answer) = fs::read_to_string(&output)
+        .ok()
+        .and_then(|o| o.lines().nth(part as usize - 1).map(str::to_string))
+        .filter(|a| !a.is_empty())
+    else {
+        println!(
+            "no answer to part {part} in {}, run day {day} first",
+            output.display()
+        );
+        return Ok(false);
+    };
+
+    let mut known = KnownAnswers::load(answers_file())?;
+    if let Some(correct) = known.get(day, part) {
+        println!("part {part} of day {day} is solved already, the answer is {correct}");
+        return Ok(true);
+    }
+
+    let guesses_file = day_dir(day).join("guesses");
+    let mut guesses = Guesses::load(&guesses_file)?;
+    if let Some(reason) = guesses.check(part, &answer) {
+        println!("not submitting: {reason}");
+        return Ok(false);
+    }
+
+    let client = client()?;
+    println!("submitting {answer} for part {part} of day {day}");
+    let mut reply = client.submit(day, part, &answer)?;
+    for _ in 0..RETRIES {
+        let Reply::Wait(wait) = reply else { break };
+        println!("answered too recently, retrying in {}s", wait.as_secs());
+        thread::sleep(wait);
+        reply = client.submit(day, part, &answer)?;
+    }
+
+    match reply {
+        Reply::Correct => {
+            known.set(day, part, answer)?;
+            known.save(answers_file())?;
+            println!("correct, recorded in answers.toml");
+        }
+        Reply::TooHigh | Reply::TooLow | Reply::Wrong => {
+            guesses.record(part, &answer, reply);
+            guesses.save(&guesses_file)?;
+            println!("{reply}, remembered in {}", guesses_file.display());
+        }
+        Reply::Wait(wait) => println!(
+            "still answered too recently, try again in {}s",
+            wait.as_secs()
+        ),
+        Reply::AlreadySolved => println!("already solved, or the part is not unlocked yet"),
+    }
+    Ok(matches!(reply, Reply::Correct | Reply::AlreadySolved))
+}
@@ -0,0 +1,106 @@
+//! Compares SipHash and the hasher of `aoclib::FastMap` on the kind of keys the days use.
+//!
+//! The workloads are synthetic, imitating the key types and map sizes of days
+//! 6, 11, 20 and 22 rather than running their solutions:
+//!
+//! ```text
+//! cargo bench -p aoclib --bench hash
+//! ```
+
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, RandomState},
+    hint::black_box,
+};
+
+use aoclib::{
+    bench::{measure, Bench, Time},
+    hash::FxBuildHasher,
+    Vec2D,
+};
+
+/// Visits every cell of a 130x130 grid, which is how large day 6 and day 20
+/// are, and looks every one of them up again.
+fn grid<S: BuildHasher + Default>() -> usize {
+    let mut times = HashMap::<Vec2D<i32>, usize, S>::default();
+    for y in 0..130 {
+        for x in 0..130 {
+            let t = times.len();
+            times.insert(Vec2D::new(x, y), t);
+        }
+    }
+    (0..130)
+        .flat_map(|y| (0..130).map(move |x| Vec2D::new(x, y)))
+        .filter(|p| times[p] % 2 == 0)
+        .count()
+}
+
+/// Sums prices by sequences of four price changes, like day 22.
+fn sequences<S: BuildHasher + Default>() -> i64 {
+    let mut map = HashMap::<[i64; 4], i64, S>::default();
+    let mut secret: i64 = 123;
+    let mut changes = [0; 4];
+    for i in 0..200_000 {
+        secret = (secret ^ (secret * 64)) % 16777216;
+        secret = (secret ^ (secret / 32)) % 16777216;
+        secret = (secret ^ (secret * 2048)) % 16777216;
+        changes = [changes[1], changes[2], changes[3], secret % 19 - 9];
+        if i >= 3 {
+            *map.entry(changes).or_default() += secret % 10;
+        }
+    }
+    map.into_values().max().unwrap_or_default()
+}
+
+/// Caches stones by value and remaining blinks, like day 11.
+fn stones<S: BuildHasher + Default>() -> u64 {
+    let mut cache = HashMap::<(u64, i32), u64, S>::default();
+    let mut hits = 0;
+    for i in 0..100_000u64 {
+        let key = (i * 2024 % 4096, (i % 75) as i32);
+        match cache.get(&key) {
+            Some(_) => hits += 1,
+            None => {
+                cache.insert(key, i);
+            }
+        }
+    }
+    hits
+}
+
+/// Times `f` with both hashers.
+fn compare<T>(name: &str, f: fn() -> T, g: fn() -> T) -> [String; 4] {
+    let config = Bench {
+        warmup: 3,
+        iterations: 20,
+    };
+    let sip = measure(config, || black_box(f()));
+    let fx = measure(config, || black_box(g()));
+    let speedup = sip.median.as_secs_f64() / fx.median.as_secs_f64();
+    [
+        name.to_string(),
+        Time(sip.median).to_string(),
+        Time(fx.median).to_string(),
+        format!("{speedup:.1}x"),
+    ]
+}
+
+fn main() {
+    let rows = [
+        compare("Vec2D grid", grid::<RandomState>, grid::<FxBuildHasher>),
+        compare(
+            "[i64; 4]",
+            sequences::<RandomState>,
+            sequences::<FxBuildHasher>,
+        ),
+        compare("(u64, i32)", stones::<RandomState>, stones::<FxBuildHasher>),
+    ];
+
+    println!(
+        "{:<12} {:>10} {:>10} {:>8}",
+        "keys", "SipHash", "FxHash", "speedup"
+    );
+    for [name, sip, fx, speedup] in rows {
+        println!("{name:<12} {sip:>10} {fx:>10} {speedup:>8}");
+    }
+}
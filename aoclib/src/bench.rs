@@ -0,0 +1,173 @@
+use std::{
+    fmt::{self, Display},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{Result, Solution};
+
+/// How often a benchmark runs each stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bench {
+    /// Untimed runs before measuring.
+    pub warmup: usize,
+    /// Timed runs. At least one run is always timed.
+    pub iterations: usize,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Bench {
+            warmup: 3,
+            iterations: 10,
+        }
+    }
+}
+
+/// Summary of the durations of repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Summarizes `samples`. Panics if there are none.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "No samples to summarize.");
+        samples.sort_unstable();
+        let n = samples.len();
+        let median = match n % 2 {
+            0 => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+            _ => samples[n / 2],
+        };
+        Stats {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / n as u32,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} / {} / {}",
+            Time(self.min),
+            Time(self.median),
+            Time(self.mean)
+        )
+    }
+}
+
+/// Timings of the separate stages of a solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Timings {
+    /// Sum of the median times of all stages.
+    pub fn total(&self) -> Duration {
+        self.parse.median + self.part1.median + self.part2.median
+    }
+}
+
+/// A duration printed with a readable unit, like `1.23ms`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Time(pub Duration);
+
+impl Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos();
+        match nanos {
+            0..1_000 => write!(f, "{nanos}ns"),
+            1_000..1_000_000 => write!(f, "{:.2}µs", nanos as f64 / 1e3),
+            1_000_000..1_000_000_000 => write!(f, "{:.2}ms", nanos as f64 / 1e6),
+            _ => write!(f, "{:.2}s", nanos as f64 / 1e9),
+        }
+    }
+}
+
+/// Runs `f` `warmup` times, then times it `iterations` times.
+pub fn measure<T>(config: Bench, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let mut samples: Vec<Duration> = (0..config.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            let out = black_box(f());
+            let elapsed = start.elapsed();
+            // dropping the result is not part of the measurement
+            drop(out);
+            elapsed
+        })
+        .collect();
+    Stats::from_samples(&mut samples)
+}
+
+/// Benchmarks parsing, part 1 and part 2 of `S` separately.
+pub fn bench<S: Solution>(input: &str, config: Bench) -> Result<Timings> {
+    // fail early instead of timing errors
+    let parsed = S::parse(input)?;
+    let parse = measure(config, || S::parse(input));
+    let part1 = measure(config, || S::part1(&parsed));
+    let part2 = measure(config, || S::part2(&parsed));
+    Ok(Timings {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn stats() {
+        let odd = Stats::from_samples(&mut millis(&[9, 1, 5]));
+        assert_eq!(odd.min, Duration::from_millis(1));
+        assert_eq!(odd.median, Duration::from_millis(5));
+        assert_eq!(odd.mean, Duration::from_millis(5));
+
+        let even = Stats::from_samples(&mut millis(&[8, 2, 4, 30]));
+        assert_eq!(even.min, Duration::from_millis(2));
+        assert_eq!(even.median, Duration::from_millis(6));
+        assert_eq!(even.mean, Duration::from_millis(11));
+        assert_eq!(even.to_string(), "2.00ms / 6.00ms / 11.00ms");
+
+        let one = Stats::from_samples(&mut millis(&[3]));
+        let three = Duration::from_millis(3);
+        assert_eq!((one.min, one.median, one.mean), (three, three, three));
+    }
+
+    #[test]
+    #[should_panic(expected = "No samples to summarize.")]
+    fn no_samples() {
+        Stats::from_samples(&mut []);
+    }
+
+    #[test]
+    fn units() {
+        let time = |nanos| Time(Duration::from_nanos(nanos)).to_string();
+        assert_eq!(time(0), "0ns");
+        assert_eq!(time(999), "999ns");
+        assert_eq!(time(1_000), "1.00µs");
+        assert_eq!(time(999_994), "999.99µs");
+        assert_eq!(time(1_000_000), "1.00ms");
+        assert_eq!(time(999_994_000), "999.99ms");
+        assert_eq!(time(1_000_000_000), "1.00s");
+        assert_eq!(time(83_250_000_000), "83.25s");
+    }
+}
@@ -0,0 +1,182 @@
+use std::ops::Add;
+
+use crate::Vec2D;
+
+/// A set of grid positions stored as one bit per cell.
+///
+/// Every cell can hold `layers` bits instead of one, for states made of a
+/// position and something small like a [`Dir4`](crate::Dir4). The bits of a
+/// cell lie next to each other, so neighbouring states share cache lines.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    layers: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// Creates an empty `width` x `height` grid with one bit per cell.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::with_layers(width, height, 1)
+    }
+
+    /// Creates an empty `width` x `height` grid with `layers` bits per cell.
+    pub fn with_layers(width: usize, height: usize, layers: usize) -> Self {
+        BitGrid {
+            width,
+            height,
+            layers,
+            words: vec![0; (width * height * layers).div_ceil(64)],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn layers(&self) -> usize {
+        self.layers
+    }
+
+    /// Returns the index of the bit of `layer` at `pos`, or None if `pos`
+    /// lies outside the grid. Panics if there is no such layer.
+    fn bit_of<I>(&self, pos: Vec2D<I>, layer: usize) -> Option<usize>
+    where
+        I: TryInto<usize> + Add<Output = I>,
+    {
+        assert!(layer < self.layers, "Layer {layer} out of bounds.");
+        let x = pos.x.try_into().ok()?;
+        let y = pos.y.try_into().ok()?;
+        (x < self.width && y < self.height).then(|| (y * self.width + x) * self.layers + layer)
+    }
+
+    /// Returns true if `pos` lies inside the grid.
+    pub fn in_bounds<I>(&self, pos: Vec2D<I>) -> bool
+    where
+        I: TryInto<usize> + Add<Output = I>,
+    {
+        self.bit_of(pos, 0).is_some()
+    }
+
+    /// Returns true if the bit of the first layer at `pos` is set.
+    pub fn contains<I>(&self, pos: Vec2D<I>) -> bool
+    where
+        I: TryInto<usize> + Add<Output = I>,
+    {
+        self.contains_in(pos, 0)
+    }
+
+    /// Returns true if the bit of `layer` at `pos` is set. Positions outside
+    /// of the grid are never set.
+    pub fn contains_in<I>(&self, pos: Vec2D<I>, layer: usize) -> bool
+    where
+        I: TryInto<usize> + Add<Output = I>,
+    {
+        self.bit_of(pos, layer)
+            .is_some_and(|bit| self.words[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Sets the bit of the first layer at `pos`. Returns true if it was not
+    /// set before, like [`HashSet::insert`](std::collections::HashSet::insert).
+    pub fn insert<I>(&mut self, pos: Vec2D<I>) -> bool
+    where
+        I: TryInto<usize> + Add<Output = I>,
+    {
+        self.insert_in(pos, 0)
+    }
+
+    /// Sets the bit of `layer` at `pos`. Returns true if it was not set
+    /// before. Panics if `pos` lies outside the grid.
+    pub fn insert_in<I>(&mut self, pos: Vec2D<I>, layer: usize) -> bool
+    where
+        I: TryInto<usize> + Add<Output = I>,
+    {
+        let bit = self.bit_of(pos, layer).expect("Position out of bounds.");
+        let word = &mut self.words[bit / 64];
+        let mask = 1 << (bit % 64);
+        let new = *word & mask == 0;
+        *word |= mask;
+        new
+    }
+
+    /// Clears the bit of the first layer at `pos`. Returns true if it was set.
+    pub fn remove<I>(&mut self, pos: Vec2D<I>) -> bool
+    where
+        I: TryInto<usize> + Add<Output = I>,
+    {
+        self.remove_in(pos, 0)
+    }
+
+    /// Clears the bit of `layer` at `pos`. Returns true if it was set.
+    pub fn remove_in<I>(&mut self, pos: Vec2D<I>, layer: usize) -> bool
+    where
+        I: TryInto<usize> + Add<Output = I>,
+    {
+        let Some(bit) = self.bit_of(pos, layer) else {
+            return false;
+        };
+        let word = &mut self.words[bit / 64];
+        let mask = 1 << (bit % 64);
+        let was = *word & mask != 0;
+        *word &= !mask;
+        was
+    }
+
+    /// Clears all bits, keeping the memory.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Returns the number of set bits in all layers.
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bits() {
+        let mut grid = BitGrid::new(9, 9);
+        assert!(grid.insert(Vec2D::new(8, 8)));
+        assert!(!grid.insert(Vec2D::new(8, 8)));
+        assert!(grid.contains(Vec2D::new(8, 8)));
+        assert!(!grid.contains(Vec2D::new(7, 8)));
+        assert!(!grid.contains(Vec2D::new(-1, 0)));
+        assert!(!grid.contains(Vec2D::new(9, 0)));
+        assert_eq!(grid.count(), 1);
+
+        assert!(grid.remove(Vec2D::new(8, 8)));
+        assert!(!grid.remove(Vec2D::new(8, 8)));
+        assert_eq!(grid.count(), 0);
+    }
+
+    #[test]
+    fn layers() {
+        let mut grid = BitGrid::with_layers(130, 130, 4);
+        for (layer, x) in [(0, 0), (1, 0), (3, 0), (3, 129)] {
+            assert!(grid.insert_in(Vec2D::new(x, 64), layer));
+        }
+        assert!(grid.contains_in(Vec2D::new(0, 64), 1));
+        assert!(!grid.contains_in(Vec2D::new(0, 64), 2));
+        assert!(!grid.contains_in(Vec2D::new(129, 64), 0));
+        assert_eq!(grid.count(), 4);
+
+        grid.clear();
+        assert_eq!(grid.count(), 0);
+        assert_eq!(grid.layers(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Position out of bounds.")]
+    fn insert_outside() {
+        BitGrid::new(2, 2).insert(Vec2D::new(2, 0));
+    }
+}
@@ -0,0 +1,152 @@
+//! Cycle detection for sequences `x0, f(x0), f(f(x0)), …`.
+//!
+//! Every detector returns `(mu, lambda)`: the sequence enters its cycle
+//! after `mu` steps and repeats every `lambda` steps from there on. Floyd
+//! and Brent need no memory but call `f` a few times per element, hashing
+//! remembers every state and calls `f` once per element. Detectors of
+//! sequences that never repeat don't return.
+
+use std::hash::Hash;
+
+use crate::FastMap;
+
+/// Finds the cycle with Floyd's tortoise and hare.
+pub fn floyd<T: PartialEq + Clone>(x0: T, mut f: impl FnMut(&T) -> T) -> (usize, usize) {
+    // the hare runs twice as fast and catches up inside the cycle
+    let mut tortoise = f(&x0);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        let half = f(&hare);
+        hare = f(&half);
+    }
+
+    // the distance between them is a multiple of lambda now, so they meet
+    // at the start of the cycle when moving at the same speed
+    let mut mu = 0;
+    tortoise = x0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        mu += 1;
+    }
+
+    let mut lambda = 1;
+    hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        lambda += 1;
+    }
+    (mu, lambda)
+}
+
+/// Finds the cycle with Brent's algorithm, which calls `f` less often than
+/// [`floyd`].
+pub fn brent<T: PartialEq + Clone>(x0: T, mut f: impl FnMut(&T) -> T) -> (usize, usize) {
+    // the tortoise teleports to the hare at every power of two, until the
+    // hare runs into it
+    let (mut power, mut lambda) = (1, 1);
+    let mut tortoise = x0.clone();
+    let mut hare = f(&x0);
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        hare = f(&hare);
+        lambda += 1;
+    }
+
+    // with a head start of lambda they meet at the start of the cycle
+    let mut tortoise = x0.clone();
+    let mut hare = x0;
+    for _ in 0..lambda {
+        hare = f(&hare);
+    }
+    let mut mu = 0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        mu += 1;
+    }
+    (mu, lambda)
+}
+
+/// Finds the cycle by remembering the index of every state.
+pub fn hashing<T: Hash + Eq + Clone>(x0: T, mut f: impl FnMut(&T) -> T) -> (usize, usize) {
+    hashing_until(x0, |x| Some(f(x))).expect("The sequence is infinite.")
+}
+
+/// Like [`hashing`], but `f` may end the sequence by returning None, in
+/// which case there is no cycle.
+pub fn hashing_until<T: Hash + Eq + Clone>(
+    x0: T,
+    mut f: impl FnMut(&T) -> Option<T>,
+) -> Option<(usize, usize)> {
+    let mut seen = FastMap::default();
+    let mut x = x0;
+    for i in 0.. {
+        if let Some(mu) = seen.insert(x.clone(), i) {
+            return Some((mu, i - mu));
+        }
+        x = f(&x)?;
+    }
+    unreachable!()
+}
+
+/// Returns the state after `n` steps, skipping whole cycles once one is
+/// found. Fine for `n` like 10^12 as long as the cycle is short.
+pub fn nth<T: Hash + Eq + Clone>(x0: T, mut f: impl FnMut(&T) -> T, n: u64) -> T {
+    let mut seen = FastMap::default();
+    let mut states = Vec::new();
+    let mut x = x0;
+    for i in 0..n {
+        if let Some(&mu) = seen.get(&x) {
+            let lambda = i - mu;
+            let offset = (n - mu) % lambda;
+            return states.swap_remove((mu + offset) as usize);
+        }
+        seen.insert(x.clone(), i);
+        let next = f(&x);
+        states.push(x);
+        x = next;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(x: &u32) -> u32 {
+        (x * x + 1) % 255
+    }
+
+    /// Returns the state after `n` steps the slow way.
+    fn naive(n: u64) -> u32 {
+        (0..n).fold(3, |x, _| step(&x))
+    }
+
+    #[test]
+    fn detectors_agree() {
+        // 3, 10, 101, 2, 5, 26, 167, 95, 101, ...
+        assert_eq!(floyd(3, step), (2, 6));
+        assert_eq!(brent(3, step), (2, 6));
+        assert_eq!(hashing(3, step), (2, 6));
+        assert_eq!(brent(0u8, |x| x.wrapping_add(1)), (0, 256));
+        assert_eq!(floyd(7, |&x| x), (0, 1));
+        assert_eq!(hashing_until(5u32, |&x| x.checked_sub(1)), None);
+    }
+
+    #[test]
+    fn nth() {
+        for n in 0..20 {
+            assert_eq!(super::nth(3, step, n), naive(n), "after {n} steps");
+        }
+        assert_eq!(
+            super::nth(3, step, 1_000_000_000_000),
+            naive(2 + 999_999_999_998 % 6)
+        );
+    }
+}
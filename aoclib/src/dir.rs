@@ -0,0 +1,217 @@
+use crate::{Vec2D, EAST, NORTH, NORTH_EAST, NORTH_WEST, SOUTH, SOUTH_EAST, SOUTH_WEST, WEST};
+
+/// One of the four cardinal directions. Ordered clockwise, starting north.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    /// All directions in clockwise order.
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    /// Returns the direction `steps` quarter turns clockwise from north.
+    const fn nth(steps: usize) -> Self {
+        Self::ALL[steps % 4]
+    }
+
+    /// Turns by 90 degrees clockwise.
+    pub const fn turn_right(self) -> Self {
+        Self::nth(self as usize + 1)
+    }
+
+    /// Turns by 90 degrees counterclockwise.
+    pub const fn turn_left(self) -> Self {
+        Self::nth(self as usize + 3)
+    }
+
+    /// Turns by 180 degrees.
+    pub const fn opposite(self) -> Self {
+        Self::nth(self as usize + 2)
+    }
+
+    /// Returns the number of quarter turns needed to face `other`, 0 to 2.
+    pub const fn turns_to(self, other: Dir4) -> u8 {
+        match (self as u8).abs_diff(other as u8) {
+            3 => 1,
+            n => n,
+        }
+    }
+
+    /// Returns true for north and south.
+    pub const fn is_vertical(self) -> bool {
+        matches!(self, Dir4::North | Dir4::South)
+    }
+
+    /// Returns the unit step in this direction, with y pointing down.
+    pub const fn vec(self) -> Vec2D<i32> {
+        match self {
+            Dir4::North => NORTH,
+            Dir4::East => EAST,
+            Dir4::South => SOUTH,
+            Dir4::West => WEST,
+        }
+    }
+
+    /// Parses an arrow `^>v<` or a letter `NESW`.
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'N' => Some(Dir4::North),
+            '>' | 'E' => Some(Dir4::East),
+            'v' | 'S' => Some(Dir4::South),
+            '<' | 'W' => Some(Dir4::West),
+            _ => None,
+        }
+    }
+}
+
+impl From<Dir4> for Vec2D<i32> {
+    fn from(dir: Dir4) -> Self {
+        dir.vec()
+    }
+}
+
+/// One of the four cardinal or four diagonal directions. Ordered clockwise,
+/// starting north.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// All directions in clockwise order.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// Returns the direction `steps` eighth turns clockwise from north.
+    const fn nth(steps: usize) -> Self {
+        Self::ALL[steps % 8]
+    }
+
+    /// Turns by 45 degrees clockwise.
+    pub const fn turn_right(self) -> Self {
+        Self::nth(self as usize + 1)
+    }
+
+    /// Turns by 45 degrees counterclockwise.
+    pub const fn turn_left(self) -> Self {
+        Self::nth(self as usize + 7)
+    }
+
+    /// Turns by 180 degrees.
+    pub const fn opposite(self) -> Self {
+        Self::nth(self as usize + 4)
+    }
+
+    /// Returns the number of eighth turns needed to face `other`, 0 to 4.
+    pub const fn turns_to(self, other: Dir8) -> u8 {
+        match (self as u8).abs_diff(other as u8) {
+            n if n > 4 => 8 - n,
+            n => n,
+        }
+    }
+
+    /// Returns true for the four diagonal directions.
+    pub const fn is_diagonal(self) -> bool {
+        self as u8 % 2 == 1
+    }
+
+    /// Returns the unit step in this direction, with y pointing down.
+    pub const fn vec(self) -> Vec2D<i32> {
+        match self {
+            Dir8::North => NORTH,
+            Dir8::NorthEast => NORTH_EAST,
+            Dir8::East => EAST,
+            Dir8::SouthEast => SOUTH_EAST,
+            Dir8::South => SOUTH,
+            Dir8::SouthWest => SOUTH_WEST,
+            Dir8::West => WEST,
+            Dir8::NorthWest => NORTH_WEST,
+        }
+    }
+
+    /// Parses an arrow `^>v<` or a letter `NESW`.
+    pub const fn from_char(c: char) -> Option<Self> {
+        match Dir4::from_char(c) {
+            Some(dir) => Some(Dir8::from_dir4(dir)),
+            None => None,
+        }
+    }
+
+    const fn from_dir4(dir: Dir4) -> Self {
+        Self::nth(dir as usize * 2)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::from_dir4(dir)
+    }
+}
+
+impl From<Dir8> for Vec2D<i32> {
+    fn from(dir: Dir8) -> Self {
+        dir.vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.turn_right().vec(), dir.vec().rotate_cw());
+            assert_eq!(dir.opposite().vec(), -dir.vec());
+        }
+        for dir in Dir8::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.opposite().vec(), -dir.vec());
+            assert_eq!(dir.turn_right().turn_right().vec(), dir.vec().rotate_cw());
+        }
+        assert_eq!(Dir4::West.turn_right(), Dir4::North);
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+    }
+
+    #[test]
+    fn turns_to() {
+        assert_eq!(Dir4::North.turns_to(Dir4::North), 0);
+        assert_eq!(Dir4::North.turns_to(Dir4::West), 1);
+        assert_eq!(Dir4::East.turns_to(Dir4::West), 2);
+        assert_eq!(Dir8::North.turns_to(Dir8::NorthWest), 1);
+        assert_eq!(Dir8::NorthEast.turns_to(Dir8::SouthWest), 4);
+        assert_eq!(Dir8::West.turns_to(Dir8::NorthEast), 3);
+    }
+
+    #[test]
+    fn parsing() {
+        let arrows: Vec<_> = "^>v<".chars().filter_map(Dir4::from_char).collect();
+        let letters: Vec<_> = "NESW".chars().filter_map(Dir4::from_char).collect();
+        assert_eq!(arrows, Dir4::ALL);
+        assert_eq!(letters, Dir4::ALL);
+        assert_eq!(Dir4::from_char('x'), None);
+        assert_eq!(Dir8::from_char('v'), Some(Dir8::South));
+        assert_eq!(Dir8::from(Dir4::West), Dir8::West);
+    }
+}
@@ -0,0 +1,180 @@
+/// A union-find over the elements `0..len`, for connectivity questions.
+///
+/// Uses path compression and union by rank, so every operation takes
+/// nearly constant time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    /// Number of elements in the set, only valid for roots.
+    size: Vec<usize>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    /// Creates `len` sets holding one element each.
+    pub fn new(len: usize) -> Self {
+        DisjointSet {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            size: vec![1; len],
+            sets: len,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// Returns the representative of the set holding `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // point everything on the way directly at the root
+        let mut x = x;
+        while self.parent[x] != root {
+            (x, self.parent[x]) = (self.parent[x], root);
+        }
+        root
+    }
+
+    /// Merges the sets holding `a` and `b`. Returns false if they already
+    /// were the same.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        // hang the flatter tree below the higher one
+        let (root, child) = match self.rank[a] < self.rank[b] {
+            true => (b, a),
+            false => (a, b),
+        };
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        self.sets -= 1;
+        true
+    }
+
+    /// Returns true if `a` and `b` are in the same set.
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the number of elements in the set holding `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Finds the first element of `walls` that separates `a` from `b`.
+    ///
+    /// The elements `0..len` are connected to their `neighbours`, unless one
+    /// of them became a wall. Walls appear in the order of `walls`. Union-find
+    /// can only join sets, so this runs backwards in time: all walls are
+    /// placed, then removed again from the last one on until `a` and `b`
+    /// meet. Returns the index into `walls`, None if `a` and `b` are still
+    /// connected in the end or never were.
+    pub fn first_separating<I>(
+        len: usize,
+        walls: &[usize],
+        mut neighbours: impl FnMut(usize) -> I,
+        a: usize,
+        b: usize,
+    ) -> Option<usize>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        // the time every wall appears, later duplicates don't count
+        let mut wall_at = vec![usize::MAX; len];
+        for (t, &w) in walls.iter().enumerate().rev() {
+            wall_at[w] = t;
+        }
+
+        let mut set = DisjointSet::new(len);
+        let mut open: Vec<bool> = wall_at.iter().map(|&t| t == usize::MAX).collect();
+        let mut join = |set: &mut DisjointSet, open: &[bool], x: usize| {
+            for n in neighbours(x) {
+                if open[n] {
+                    set.union(x, n);
+                }
+            }
+        };
+
+        for x in (0..len).filter(|&x| open[x]) {
+            join(&mut set, &open, x);
+        }
+        if set.same(a, b) {
+            return None;
+        }
+        for (t, &w) in walls.iter().enumerate().rev() {
+            if wall_at[w] != t {
+                continue;
+            }
+            open[w] = true;
+            join(&mut set, &open, w);
+            if set.same(a, b) {
+                return Some(t);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_find() {
+        let mut set = DisjointSet::new(6);
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+        assert!(set.same(0, 3));
+        assert!(!set.same(0, 4));
+        assert_eq!(set.size(2), 4);
+        assert_eq!(set.size(5), 1);
+        assert_eq!(set.sets(), 3);
+        assert_eq!(set.len(), 6);
+    }
+
+    #[test]
+    fn first_separating() {
+        // a path 0 - 1 - 2 - 3 - 4 next to a detour 1 - 5 - 3
+        let edges = [(0, 1), (1, 2), (2, 3), (3, 4), (1, 5), (5, 3)];
+        let neighbours = |x: usize| {
+            edges
+                .iter()
+                .filter_map(move |&(a, b)| match x {
+                    _ if x == a => Some(b),
+                    _ if x == b => Some(a),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let first = |walls: &[usize]| DisjointSet::first_separating(6, walls, neighbours, 0, 4);
+        assert_eq!(first(&[2, 5, 1]), Some(1));
+        assert_eq!(first(&[5, 5, 2]), Some(2));
+        assert_eq!(first(&[2]), None);
+        assert_eq!(first(&[]), None);
+        assert_eq!(first(&[4, 2]), Some(0));
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    fmt::{self, Display},
+    io,
+    path::PathBuf,
+    str::FromStr,
+};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The puzzle input file does not exist.
+    MissingInput(PathBuf),
+    /// The puzzle input is malformed. Lines and columns start at 1.
+    Parse {
+        line: usize,
+        column: usize,
+        msg: String,
+    },
+    Io(io::Error),
+    /// The session cookie is missing or was not accepted by the server.
+    Session(String),
+    /// A request failed, like a 404 for a puzzle that is not unlocked yet.
+    Http {
+        url: String,
+        msg: String,
+    },
+    /// Refused to overwrite an existing file.
+    Exists(PathBuf),
+    /// Puzzles only have part 1 and part 2.
+    Part(u8),
+    /// A puzzle parameter does not parse as the type the day expects.
+    Param {
+        key: String,
+        value: String,
+        msg: String,
+    },
+}
+
+impl Error {
+    /// Creates a parse error at the 1-based `line` and `column`.
+    pub fn parse(line: usize, column: usize, msg: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            column,
+            msg: msg.into(),
+        }
+    }
+
+    /// Creates a parse error pointing at `field`, which has to be a slice of `input`.
+    pub fn at(input: &str, field: &str, msg: impl Into<String>) -> Self {
+        let (line, column) = locate(input, field);
+        Error::parse(line, column, msg)
+    }
+
+    /// Moves a parse error found in `part`, a slice of `input`, to its position in `input`.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        match self {
+            Error::Parse { line, column, msg } => {
+                let (l, c) = locate(input, part);
+                let column = if line == 1 { c + column - 1 } else { column };
+                Error::parse(l + line - 1, column, msg)
+            }
+            e => e,
+        }
+    }
+
+    /// Renders the error, quoting the offending line of `input` for parse errors.
+    pub fn report(&self, input: &str) -> String {
+        let mut report = format!("error: {self}");
+        if let Error::Parse { line, column, .. } = self {
+            if let Some(l) = line.checked_sub(1).and_then(|i| input.lines().nth(i)) {
+                let pad = " ".repeat(line.to_string().len());
+                report += &format!("\n{pad} |\n{line} | {l}\n{pad} | {:>column$}", "^");
+            }
+        }
+        report
+    }
+
+    /// Prints the report to stderr and exits with a failure code.
+    pub fn exit(&self, input: &str) -> ! {
+        eprintln!("{}", self.report(input));
+        std::process::exit(1)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingInput(path) => write!(f, "missing input file `{}`", path.display()),
+            Error::Parse { line, column, msg } => {
+                write!(f, "{msg} (line {line}, column {column})")
+            }
+            Error::Io(e) => write!(f, "I/O error: {e}"),
+            Error::Session(msg) => write!(f, "session error: {msg}"),
+            Error::Http { url, msg } => write!(f, "request to {url} failed: {msg}"),
+            Error::Exists(path) => write!(f, "refusing to overwrite `{}`", path.display()),
+            Error::Part(part) => write!(f, "invalid part {part}, expected 1 or 2"),
+            Error::Param { key, value, msg } => {
+                write!(f, "invalid value `{value}` for parameter `{key}`: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Error::Io(value)
+    }
+}
+
+/// Returns the 1-based (line, column) of `field` inside `input`.
+///
+/// Falls back to the very start if `field` is not a slice of `input`.
+fn locate(input: &str, field: &str) -> (usize, usize) {
+    let offset = (field.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|&o| o <= input.len() && input.is_char_boundary(o))
+        .unwrap_or(0);
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Parses `field`, a slice of `input`, reporting its position on failure.
+pub fn parse_field<T>(input: &str, field: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    field
+        .parse()
+        .map_err(|e| Error::at(input, field, format!("invalid value `{field}`: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1 2 3\n4 x 6\n";
+
+    fn position(e: &Error) -> (usize, usize) {
+        match e {
+            Error::Parse { line, column, .. } => (*line, *column),
+            e => panic!("expected a parse error, got {e}"),
+        }
+    }
+
+    #[test]
+    fn locate_fields() {
+        assert_eq!(locate(INPUT, &INPUT[0..1]), (1, 1));
+        assert_eq!(locate(INPUT, &INPUT[8..9]), (2, 3));
+        assert_eq!(locate(INPUT, &INPUT[12..]), (3, 1));
+        // not a slice of the input
+        assert_eq!(locate(INPUT, "x"), (1, 1));
+    }
+
+    #[test]
+    fn field_errors() {
+        let fields: Vec<&str> = INPUT.split_whitespace().collect();
+        assert_eq!(parse_field::<u8>(INPUT, fields[3]).unwrap(), 4);
+        let e = parse_field::<u8>(INPUT, fields[4]).unwrap_err();
+        assert_eq!(position(&e), (2, 3));
+        assert!(e.to_string().starts_with("invalid value `x`: "));
+
+        let e = Error::at(INPUT, fields[5], "too large");
+        assert_eq!(e.to_string(), "too large (line 2, column 5)");
+    }
+
+    #[test]
+    fn within() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let e = Error::parse(1, 3, "bad").within(INPUT, line);
+        assert_eq!(position(&e), (2, 3));
+        let e = Error::parse(2, 2, "bad").within(INPUT, &INPUT[2..]);
+        assert_eq!(position(&e), (2, 2));
+        assert!(matches!(
+            Error::Exists("x".into()).within(INPUT, line),
+            Error::Exists(_)
+        ));
+    }
+
+    #[test]
+    fn report() {
+        let e = Error::parse(2, 3, "bad");
+        assert_eq!(
+            e.report(INPUT),
+            "error: bad (line 2, column 3)\n  |\n2 | 4 x 6\n  |   ^"
+        );
+        // no line to quote
+        for e in [Error::parse(0, 1, "bad"), Error::parse(9, 1, "bad")] {
+            assert_eq!(e.report(INPUT), format!("error: {e}"));
+        }
+    }
+}
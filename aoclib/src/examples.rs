@@ -0,0 +1,128 @@
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+};
+
+use crate::{params, read_input, verify::parse_sections, Solution};
+
+/// Generates a `#[test]` running a day against all of its example files.
+///
+/// Expects the name of a [`Solution`] defined in the invoking module:
+///
+/// ```ignore
+/// aoclib::examples!(Day1);
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($day:ident) => {
+        #[cfg(test)]
+        mod examples {
+            #[test]
+            fn examples() {
+                $crate::examples::check::<super::$day>(env!("CARGO_MANIFEST_DIR"));
+            }
+        }
+    };
+}
+
+/// Returns true for example files, named `example` or `exampleN`.
+fn is_example(name: &str) -> bool {
+    name.strip_prefix("example")
+        .is_some_and(|n| n.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Runs `f`, turning a panic into an error message.
+fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|e| {
+        e.downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| e.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+/// Solves every example file in `dir` with `S` and compares the answers to
+/// the ones annotated in `dir/examples.toml`:
+///
+/// ```toml
+/// [example2]
+/// part1 = "161"
+/// part2 = "48"
+/// ```
+///
+/// Every example is parsed, parts without an annotated answer are skipped.
+/// `param.KEY = value` entries are set as [`params`] while solving the
+/// example. Panics with a list of all mismatches if any.
+pub fn check<S: Solution>(dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+    let annotations = dir.join("examples.toml");
+    let text = fs::read_to_string(&annotations).unwrap_or_default();
+    let annotated = parse_sections(
+        &text,
+        "exampleN",
+        |s| is_example(s).then(|| s.to_string()),
+        true,
+    )
+    .unwrap_or_else(|e| panic!("{}: {}", annotations.display(), e.report(&text)));
+
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .expect("Day directory is readable.")
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| is_example(name))
+        .collect();
+    names.sort();
+
+    let mut failures = Vec::new();
+    for name in annotated.keys().filter(|name| !names.contains(name)) {
+        failures.push(format!("{name}: annotated in examples.toml but missing"));
+    }
+
+    for name in &names {
+        let section = annotated.get(name).cloned().unwrap_or_default();
+        let input = read_input(dir.join(name)).unwrap_or_else(|e| panic!("{e}"));
+        failures.extend(params::with(section.params, || {
+            check_example::<S>(name, &input, &section.parts)
+        }));
+    }
+
+    if !failures.is_empty() {
+        panic!(
+            "{} example check(s) failed:\n{}",
+            failures.len(),
+            failures.join("\n")
+        );
+    }
+}
+
+/// Solves the example `name` and returns what differs from the `expected`
+/// answers.
+fn check_example<S: Solution>(
+    name: &str,
+    input: &str,
+    expected: &[Option<String>; 2],
+) -> Vec<String> {
+    let input = match catch(|| S::parse(input)) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(e)) => return vec![format!("{name}: {}", e.report(input))],
+        Err(msg) => return vec![format!("{name}: parse panicked: {msg}")],
+    };
+
+    let mut failures = Vec::new();
+    let solvers: [&dyn Fn() -> String; 2] = [&|| S::part1(&input).to_string(), &|| {
+        S::part2(&input).to_string()
+    }];
+    for (part, (expected, solve)) in (1..).zip(expected.iter().zip(solvers)) {
+        let Some(expected) = expected else {
+            continue;
+        };
+        match catch(solve) {
+            Ok(answer) if &answer == expected => {}
+            Ok(answer) => failures.push(format!(
+                "{name} part {part}: expected {expected}, got {answer}"
+            )),
+            Err(msg) => failures.push(format!("{name} part {part}: panicked: {msg}")),
+        }
+    }
+    failures
+}
@@ -0,0 +1,158 @@
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use ureq::{Agent, AgentBuilder};
+
+use crate::{Error, Result};
+
+/// The Advent of Code website.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request, so the AoC team knows who to contact.
+pub const USER_AGENT: &str = concat!(
+    "github.com/michihupf/aoc24 aoclib/",
+    env!("CARGO_PKG_VERSION"),
+    " (input downloader)"
+);
+
+/// Returns the path of the session file, `~/.aocrc`.
+pub fn session_file() -> PathBuf {
+    let home = env::var_os("HOME").unwrap_or_default();
+    Path::new(&home).join(".aocrc")
+}
+
+/// Reads the session cookie stored in `path`.
+pub fn read_session(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    let session = fs::read_to_string(path).map_err(|e| match e.kind() {
+        ErrorKind::NotFound => Error::Session(format!(
+            "no session file at `{}`, place your AoC session cookie there",
+            path.display()
+        )),
+        _ => Error::Io(e),
+    })?;
+
+    let session = session.trim();
+    if session.is_empty() {
+        return Err(Error::Session(format!("`{}` is empty", path.display())));
+    }
+    Ok(session.to_string())
+}
+
+/// A client for the puzzles of one year.
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    year: u16,
+    session: String,
+    agent: Agent,
+}
+
+impl Client {
+    /// Creates a client talking to `base_url`, usually [`BASE_URL`].
+    pub fn new(base_url: impl Into<String>, year: u16, session: impl Into<String>) -> Self {
+        Client {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            year,
+            session: session.into(),
+            agent: AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Creates a client with the session read from `~/.aocrc`.
+    pub fn from_session_file(base_url: impl Into<String>, year: u16) -> Result<Self> {
+        let session = read_session(session_file())?;
+        Ok(Self::new(base_url, year, session))
+    }
+
+    /// Returns the URL of `path` below the pages of the year.
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("{}/{}/{path}", self.base_url, self.year)
+    }
+
+    /// Creates a request for `url` carrying the session cookie.
+    pub(crate) fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    /// Downloads the input of `day`.
+    pub fn input(&self, day: u8) -> Result<String> {
+        let url = self.url(&format!("day/{day}/input"));
+        body(&url, self.request("GET", &url).call())
+    }
+
+    /// Downloads the input of `day` to `path`. Refuses to overwrite an
+    /// existing input.
+    pub fn save_input(&self, day: u8, path: impl AsRef<Path>) -> Result<String> {
+        let path = path.as_ref();
+        if path.exists() {
+            return Err(Error::Exists(path.to_path_buf()));
+        }
+
+        let input = self.input(day)?;
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .map_err(|e| match e.kind() {
+                ErrorKind::AlreadyExists => Error::Exists(path.to_path_buf()),
+                _ => Error::Io(e),
+            })?;
+        file.write_all(input.as_bytes())?;
+        Ok(input)
+    }
+
+    /// Returns the input of `day` cached at `path`, downloading it first if
+    /// it is not there yet.
+    pub fn cached_input(&self, day: u8, path: impl AsRef<Path>) -> Result<String> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(input) => Ok(input),
+            Err(e) if e.kind() == ErrorKind::NotFound => self.save_input(day, path),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Returns the body of a successful `response` to a request to `url`.
+pub(crate) fn body(
+    url: &str,
+    response: std::result::Result<ureq::Response, ureq::Error>,
+) -> Result<String> {
+    let http = |msg: String| Error::Http {
+        url: url.to_string(),
+        msg,
+    };
+    match response {
+        Ok(response) => {
+            let body = response.into_string()?;
+            // an expired session gets a login page instead of an error
+            match body.contains("Please log in") {
+                true => Err(logged_out()),
+                false => Ok(body),
+            }
+        }
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(match status {
+                _ if body.contains("Please log in") => logged_out(),
+                404 => http("not found (is the puzzle unlocked yet?)".to_string()),
+                _ => http(format!("status {status}: {}", body.trim())),
+            })
+        }
+        Err(ureq::Error::Transport(e)) => Err(http(match e.message() {
+            Some(msg) => format!("{}: {msg}", e.kind()),
+            None => e.kind().to_string(),
+        })),
+    }
+}
+
+fn logged_out() -> Error {
+    Error::Session("not logged in, the session cookie is invalid or expired".to_string())
+}
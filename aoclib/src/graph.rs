@@ -0,0 +1,438 @@
+//! Graphs over interned node labels.
+//!
+//! Nodes are numbered `0..len()` in the order they are first seen, so
+//! algorithms work on plain indices and labels are only looked up for
+//! input and output. Every node keeps an adjacency list for iteration and a
+//! bitset for O(1) edge lookups and fast neighbourhood intersections.
+
+use std::{borrow::Borrow, collections::VecDeque, hash::Hash, ops::Range};
+
+use crate::FastMap;
+
+/// A set of small indices stored as bits, grown on demand.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct BitSet(Vec<u64>);
+
+impl BitSet {
+    /// Returns the set of `0..n`.
+    fn full(n: usize) -> Self {
+        let mut words = vec![u64::MAX; n.div_ceil(64)];
+        if !n.is_multiple_of(64) {
+            *words.last_mut().unwrap() = (1 << (n % 64)) - 1;
+        }
+        BitSet(words)
+    }
+
+    fn insert(&mut self, i: usize) {
+        if self.0.len() <= i / 64 {
+            self.0.resize(i / 64 + 1, 0);
+        }
+        self.0[i / 64] |= 1 << (i % 64);
+    }
+
+    fn remove(&mut self, i: usize) {
+        if let Some(word) = self.0.get_mut(i / 64) {
+            *word &= !(1 << (i % 64));
+        }
+    }
+
+    fn contains(&self, i: usize) -> bool {
+        self.0.get(i / 64).is_some_and(|w| w & (1 << (i % 64)) != 0)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.iter().all(|&w| w == 0)
+    }
+
+    fn count(&self) -> usize {
+        self.0.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns the intersection with `other`.
+    fn and(&self, other: &BitSet) -> BitSet {
+        BitSet(self.0.iter().zip(&other.0).map(|(a, b)| a & b).collect())
+    }
+
+    /// Returns the elements not in `other`.
+    fn and_not(&self, other: &BitSet) -> BitSet {
+        let other = other.0.iter().chain(std::iter::repeat(&0));
+        BitSet(self.0.iter().zip(other).map(|(a, b)| a & !b).collect())
+    }
+
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().enumerate().flat_map(|(i, &w)| {
+            let mut w = w;
+            std::iter::from_fn(move || {
+                (w != 0).then(|| {
+                    let bit = w.trailing_zeros() as usize;
+                    w &= w - 1;
+                    i * 64 + bit
+                })
+            })
+        })
+    }
+}
+
+/// A directed or undirected graph whose nodes are labelled with `N`.
+///
+/// Self loops and repeated edges are ignored.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    directed: bool,
+    labels: Vec<N>,
+    ids: FastMap<N, usize>,
+    adjacent: Vec<Vec<usize>>,
+    bits: Vec<BitSet>,
+}
+
+impl<N> Graph<N>
+where
+    N: Eq + Hash + Clone,
+{
+    /// Creates an empty graph where every edge goes both ways.
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    /// Creates an empty graph where edges go from `a` to `b` only.
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    fn new(directed: bool) -> Self {
+        Graph {
+            directed,
+            labels: Vec::new(),
+            ids: FastMap::default(),
+            adjacent: Vec::new(),
+            bits: Vec::new(),
+        }
+    }
+
+    /// Returns the index of `label`, adding a node if it is new.
+    pub fn node<Q>(&mut self, label: &Q) -> usize
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = N> + ?Sized,
+    {
+        if let Some(&id) = self.ids.get(label) {
+            return id;
+        }
+        let id = self.labels.len();
+        self.labels.push(label.to_owned());
+        self.ids.insert(label.to_owned(), id);
+        self.adjacent.push(Vec::new());
+        self.bits.push(BitSet::default());
+        id
+    }
+
+    /// Returns the index of `label` if it is a node.
+    pub fn id<Q>(&self, label: &Q) -> Option<usize>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.ids.get(label).copied()
+    }
+
+    /// Returns the label of node `id`.
+    pub fn label(&self, id: usize) -> &N {
+        &self.labels[id]
+    }
+
+    /// Adds an edge between two labels, adding their nodes if needed.
+    /// Returns their indices.
+    pub fn add_edge<Q>(&mut self, a: &Q, b: &Q) -> (usize, usize)
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = N> + ?Sized,
+    {
+        let (a, b) = (self.node(a), self.node(b));
+        self.connect(a, b);
+        (a, b)
+    }
+
+    /// Adds an edge between the nodes `a` and `b`.
+    pub fn connect(&mut self, a: usize, b: usize) {
+        if a == b || self.bits[a].contains(b) {
+            return;
+        }
+        self.adjacent[a].push(b);
+        self.bits[a].insert(b);
+        if !self.directed {
+            self.adjacent[b].push(a);
+            self.bits[b].insert(a);
+        }
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Returns the indices of all nodes.
+    pub fn nodes(&self) -> Range<usize> {
+        0..self.len()
+    }
+
+    /// Returns the nodes an edge from `id` leads to, in insertion order.
+    pub fn neighbors(&self, id: usize) -> &[usize] {
+        &self.adjacent[id]
+    }
+
+    /// Returns the number of edges leaving `id`.
+    pub fn degree(&self, id: usize) -> usize {
+        self.adjacent[id].len()
+    }
+
+    /// Returns true if there is an edge from `a` to `b`.
+    pub fn has_edge(&self, a: usize, b: usize) -> bool {
+        self.bits[a].contains(b)
+    }
+
+    /// Returns every triangle once, as indices in increasing order.
+    ///
+    /// Meant for undirected graphs.
+    pub fn triangles(&self) -> Vec<[usize; 3]> {
+        let mut triangles = Vec::new();
+        for a in self.nodes() {
+            for &b in self.adjacent[a].iter().filter(|&&b| b > a) {
+                let common = self.bits[a].and(&self.bits[b]);
+                triangles.extend(common.iter().filter(|&c| c > b).map(|c| [a, b, c]));
+            }
+        }
+        triangles
+    }
+
+    /// Returns all maximal cliques, each sorted by index.
+    ///
+    /// Uses Bron–Kerbosch with pivoting. Meant for undirected graphs.
+    pub fn maximal_cliques(&self) -> Vec<Vec<usize>> {
+        let mut cliques = Vec::new();
+        if self.is_empty() {
+            return cliques;
+        }
+        self.bron_kerbosch(
+            &mut Vec::new(),
+            BitSet::full(self.len()),
+            BitSet::default(),
+            &mut cliques,
+        );
+        cliques
+    }
+
+    /// Extends the clique `r` by nodes of `p`, never by nodes of `x`, which
+    /// were covered by earlier branches already.
+    fn bron_kerbosch(
+        &self,
+        r: &mut Vec<usize>,
+        mut p: BitSet,
+        mut x: BitSet,
+        cliques: &mut Vec<Vec<usize>>,
+    ) {
+        if p.is_empty() {
+            if x.is_empty() {
+                let mut clique = r.clone();
+                clique.sort_unstable();
+                cliques.push(clique);
+            }
+            return;
+        }
+
+        // every maximal clique holds the pivot or one of its non-neighbours, so
+        // only those need a branch. the pivot with most neighbours in p leaves
+        // the fewest.
+        let pivot = p
+            .iter()
+            .chain(x.iter())
+            .max_by_key(|&u| p.and(&self.bits[u]).count())
+            .unwrap();
+        for v in p.and_not(&self.bits[pivot]).iter().collect::<Vec<_>>() {
+            r.push(v);
+            self.bron_kerbosch(r, p.and(&self.bits[v]), x.and(&self.bits[v]), cliques);
+            r.pop();
+            p.remove(v);
+            x.insert(v);
+        }
+    }
+
+    /// Returns the connected components, each sorted by index and ordered by
+    /// their smallest node. Edge directions are ignored.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut component = vec![usize::MAX; self.len()];
+        let mut components = Vec::new();
+        // edges leading to a node, to walk directed edges backwards
+        let incoming = match self.directed {
+            true => {
+                let mut incoming = vec![Vec::new(); self.len()];
+                for (a, adjacent) in self.adjacent.iter().enumerate() {
+                    for &b in adjacent {
+                        incoming[b].push(a);
+                    }
+                }
+                incoming
+            }
+            false => Vec::new(),
+        };
+
+        for start in self.nodes() {
+            if component[start] != usize::MAX {
+                continue;
+            }
+            let mut nodes = vec![start];
+            component[start] = components.len();
+            let mut i = 0;
+            while let Some(&node) = nodes.get(i) {
+                let back = incoming.get(node).map_or(&[][..], Vec::as_slice);
+                for &next in self.adjacent[node].iter().chain(back) {
+                    if component[next] == usize::MAX {
+                        component[next] = components.len();
+                        nodes.push(next);
+                    }
+                }
+                i += 1;
+            }
+            nodes.sort_unstable();
+            components.push(nodes);
+        }
+        components
+    }
+
+    /// Orders all nodes so that every edge points forward, or returns None
+    /// if there is a cycle.
+    pub fn topo_sort(&self) -> Option<Vec<usize>> {
+        self.topo_sort_of(&self.nodes().collect::<Vec<_>>())
+    }
+
+    /// Orders the distinct `nodes` so that every edge between them points
+    /// forward, or returns None if they form a cycle. Edges to other nodes
+    /// are ignored.
+    ///
+    /// Uses Kahn's algorithm. Nodes that could go in either order keep their
+    /// order in `nodes`.
+    pub fn topo_sort_of(&self, nodes: &[usize]) -> Option<Vec<usize>> {
+        let mut inside = BitSet::default();
+        nodes.iter().for_each(|&n| inside.insert(n));
+        let mut incoming = FastMap::default();
+        for &n in nodes {
+            for &m in self.adjacent[n].iter().filter(|&&m| inside.contains(m)) {
+                *incoming.entry(m).or_insert(0) += 1;
+            }
+        }
+
+        let mut ready: VecDeque<_> = nodes
+            .iter()
+            .copied()
+            .filter(|n| !incoming.contains_key(n))
+            .collect();
+        let mut sorted = Vec::with_capacity(nodes.len());
+        while let Some(n) = ready.pop_front() {
+            sorted.push(n);
+            for &m in self.adjacent[n].iter().filter(|&&m| inside.contains(m)) {
+                let count = incoming.get_mut(&m).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    ready.push_back(m);
+                }
+            }
+        }
+        (sorted.len() == nodes.len()).then_some(sorted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A clique of four with a tail, and a separate edge.
+    fn network() -> Graph<String> {
+        let mut graph = Graph::undirected();
+        for edge in [
+            "a-b", "a-c", "b-c", "c-d", "b-d", "a-d", "d-e", "x-y", "a-b",
+        ] {
+            let (a, b) = edge.split_once('-').unwrap();
+            graph.add_edge(a, b);
+        }
+        graph
+    }
+
+    #[test]
+    fn interning() {
+        let graph = network();
+        assert_eq!(graph.len(), 7);
+        assert_eq!(graph.id("c"), Some(2));
+        assert_eq!(graph.id("z"), None);
+        assert_eq!(graph.label(4), "e");
+        assert_eq!(graph.degree(0), 3);
+        assert_eq!(graph.degree(4), 1);
+        assert!(graph.has_edge(4, 3) && graph.has_edge(3, 4));
+        assert!(!graph.has_edge(4, 0));
+    }
+
+    #[test]
+    fn cliques() {
+        let graph = network();
+        assert_eq!(
+            graph.triangles(),
+            [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]]
+        );
+
+        let mut cliques = graph.maximal_cliques();
+        cliques.sort();
+        assert_eq!(cliques, [vec![0, 1, 2, 3], vec![3, 4], vec![5, 6]]);
+    }
+
+    #[test]
+    fn cliques_beyond_one_word() {
+        // a complete graph on 70 nodes plus a pendant node
+        let mut graph = Graph::undirected();
+        for a in 0..70 {
+            for b in a + 1..70 {
+                graph.add_edge(&a, &b);
+            }
+        }
+        graph.add_edge(&69, &70);
+        let mut cliques = graph.maximal_cliques();
+        cliques.sort_by_key(Vec::len);
+        assert_eq!(cliques.len(), 2);
+        assert_eq!(cliques[0], [69, 70]);
+        assert_eq!(cliques[1], (0..70).collect::<Vec<_>>());
+        assert_eq!(graph.triangles().len(), 70 * 69 * 68 / 6);
+    }
+
+    #[test]
+    fn components() {
+        assert_eq!(network().components(), [vec![0, 1, 2, 3, 4], vec![5, 6]]);
+
+        let mut graph = Graph::directed();
+        graph.add_edge("b", "a");
+        graph.add_edge("c", "a");
+        graph.node("d");
+        assert_eq!(graph.components(), [vec![0, 1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn topo_sort() {
+        let mut graph = Graph::directed();
+        for (a, b) in [(97, 13), (97, 61), (61, 13), (75, 97), (75, 13), (29, 13)] {
+            graph.add_edge(&a, &b);
+        }
+        let order: Vec<_> = graph
+            .topo_sort()
+            .unwrap()
+            .into_iter()
+            .map(|id| *graph.label(id))
+            .collect();
+        assert_eq!(order, [75, 29, 97, 61, 13]);
+
+        let (p13, p29) = (graph.id(&13).unwrap(), graph.id(&29).unwrap());
+        assert_eq!(graph.topo_sort_of(&[p13, p29]), Some(vec![p29, p13]));
+
+        graph.add_edge(&13, &75);
+        assert_eq!(graph.topo_sort(), None);
+    }
+}
@@ -0,0 +1,304 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, Index, IndexMut},
+};
+
+use crate::{Error, Result, Vec2D, CARDINAL, DIAGONAL};
+
+/// A rectangular grid stored row-major in a flat `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a `width` x `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from row-major `cells`.
+    ///
+    /// Panics if `cells` does not hold exactly `width * height` values.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Grid size mismatch.");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses puzzle text line by line, converting every character with `f`.
+    ///
+    /// Fails on lines of differing length and on characters `f` rejects.
+    pub fn try_parse_with(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.lines() {
+            let before = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                let cell = f(c).ok_or_else(|| {
+                    Error::parse(height + 1, x + 1, format!("unexpected character `{c}`"))
+                })?;
+                cells.push(cell);
+            }
+            let w = cells.len() - before;
+            let expected = *width.get_or_insert(w);
+            if w != expected {
+                return Err(Error::parse(
+                    height + 1,
+                    w.min(expected) + 1,
+                    format!("expected {expected} columns, found {w}"),
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// Parses puzzle text line by line, converting every character with `f`.
+    ///
+    /// Panics if the lines are not all of the same length.
+    pub fn parse_with(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        Self::try_parse_with(input, |c| Some(f(c))).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Parses puzzle text into a grid of characters (or anything built from one).
+    pub fn parse(input: &str) -> Self
+    where
+        T: From<char>,
+    {
+        Self::parse_with(input, T::from)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the flat index of `pos` or None if it lies outside the grid.
+    fn index_of<I>(&self, pos: Vec2D<I>) -> Option<usize>
+    where
+        I: TryInto<usize> + Add<Output = I>,
+    {
+        let x = pos.x.try_into().ok()?;
+        let y = pos.y.try_into().ok()?;
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    /// Returns true if `pos` lies inside the grid.
+    pub fn in_bounds<I>(&self, pos: Vec2D<I>) -> bool
+    where
+        I: TryInto<usize> + Add<Output = I>,
+    {
+        self.index_of(pos).is_some()
+    }
+
+    pub fn get<I>(&self, pos: Vec2D<I>) -> Option<&T>
+    where
+        I: TryInto<usize> + Add<Output = I>,
+    {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut<I>(&mut self, pos: Vec2D<I>) -> Option<&mut T>
+    where
+        I: TryInto<usize> + Add<Output = I>,
+    {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Swaps the values at `a` and `b`.
+    pub fn swap<I>(&mut self, a: Vec2D<I>, b: Vec2D<I>)
+    where
+        I: TryInto<usize> + Add<Output = I>,
+    {
+        let a = self.index_of(a).expect("Position out of bounds.");
+        let b = self.index_of(b).expect("Position out of bounds.");
+        self.cells.swap(a, b);
+    }
+
+    /// Iterates over all positions in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Vec2D<i32>> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Vec2D::new((i % width) as i32, (i / width) as i32))
+    }
+
+    /// Iterates over all cells and their positions in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2D<i32>, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Iterates over the rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Returns the in-bound cardinal neighbours of `pos`.
+    pub fn neighbours(&self, pos: Vec2D<i32>) -> impl Iterator<Item = Vec2D<i32>> + '_ {
+        CARDINAL
+            .into_iter()
+            .map(move |d| pos + d)
+            .filter(|&n| self.in_bounds(n))
+    }
+
+    /// Returns the in-bound cardinal and diagonal neighbours of `pos`.
+    pub fn neighbours8(&self, pos: Vec2D<i32>) -> impl Iterator<Item = Vec2D<i32>> + '_ {
+        CARDINAL
+            .into_iter()
+            .chain(DIAGONAL)
+            .map(move |d| pos + d)
+            .filter(|&n| self.in_bounds(n))
+    }
+
+    /// Returns the position of the first cell equal to `value` in reading order.
+    pub fn find(&self, value: &T) -> Option<Vec2D<i32>>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, v)| *v == value).map(|(pos, _)| pos)
+    }
+}
+
+impl<T, I> Index<Vec2D<I>> for Grid<T>
+where
+    I: TryInto<usize> + Add<Output = I>,
+{
+    type Output = T;
+
+    fn index(&self, pos: Vec2D<I>) -> &Self::Output {
+        self.get(pos).expect("Position out of bounds.")
+    }
+}
+
+impl<T, I> IndexMut<Vec2D<I>> for Grid<T>
+where
+    I: TryInto<usize> + Add<Output = I>,
+{
+    fn index_mut(&mut self, pos: Vec2D<I>) -> &mut Self::Output {
+        self.get_mut(pos).expect("Position out of bounds.")
+    }
+}
+
+impl<T> Display for Grid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the line and column of a parse error.
+    fn position(e: Error) -> (usize, usize) {
+        match e {
+            Error::Parse { line, column, .. } => (line, column),
+            e => panic!("expected a parse error, got {e}"),
+        }
+    }
+
+    fn digit(c: char) -> Option<u32> {
+        c.to_digit(10)
+    }
+
+    #[test]
+    fn parse() {
+        let grid = Grid::try_parse_with("123\n456\n", digit).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Vec2D::new(2, 1)], 6);
+        assert_eq!(Grid::<char>::parse("").width(), 0);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let ragged = Grid::try_parse_with("123\n45\n789", digit).unwrap_err();
+        assert_eq!(position(ragged), (2, 3));
+        let longer = Grid::try_parse_with("12\n345", digit).unwrap_err();
+        assert_eq!(position(longer), (2, 3));
+        let rejected = Grid::try_parse_with("123\n4x6", digit).unwrap_err();
+        assert_eq!(
+            rejected.to_string(),
+            "unexpected character `x` (line 2, column 2)"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 2 columns, found 1")]
+    fn parse_ragged() {
+        Grid::<char>::parse("ab\nc");
+    }
+
+    #[test]
+    fn bounds() {
+        let grid = Grid::<char>::parse("ab\ncd");
+        assert_eq!(grid.get(Vec2D::new(1, 1)), Some(&'d'));
+        for pos in [
+            Vec2D::new(-1, 0),
+            Vec2D::new(0, -1),
+            Vec2D::new(2, 0),
+            Vec2D::new(0, 2),
+        ] {
+            assert!(!grid.in_bounds(pos));
+            assert_eq!(grid.get(pos), None);
+        }
+        assert!(grid.in_bounds(Vec2D::new(1usize, 0)));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        let count = |pos| (grid.neighbours(pos).count(), grid.neighbours8(pos).count());
+        assert_eq!(count(Vec2D::new(0, 0)), (2, 3));
+        assert_eq!(count(Vec2D::new(2, 2)), (2, 3));
+        assert_eq!(count(Vec2D::new(1, 0)), (3, 5));
+        assert_eq!(count(Vec2D::new(1, 1)), (4, 8));
+        assert!(grid.neighbours(Vec2D::new(0, 0)).all(|n| grid.in_bounds(n)));
+    }
+
+    #[test]
+    fn find_and_swap() {
+        let mut grid = Grid::<char>::parse("#S.\n..E");
+        assert_eq!(grid.find(&'E'), Some(Vec2D::new(2, 1)));
+        assert_eq!(grid.find(&'@'), None);
+        grid.swap(Vec2D::new(1, 0), Vec2D::new(2, 1));
+        assert_eq!(grid.find(&'S'), Some(Vec2D::new(2, 1)));
+        assert_eq!(grid[Vec2D::new(1, 0)], 'E');
+    }
+
+    #[test]
+    fn display() {
+        let text = "#.#\n.@.\n#.#";
+        assert_eq!(Grid::<char>::parse(text).to_string(), text);
+    }
+}
@@ -0,0 +1,124 @@
+//! A fast hasher for the small keys of puzzles, like integers, [`Vec2D`]s
+//! and short arrays.
+//!
+//! It is the hash of Firefox and rustc: every word is mixed in with a
+//! rotation, a xor and a multiplication. Unlike the default SipHash it does
+//! not protect against inputs crafted to collide, which puzzle inputs are
+//! not. `cargo bench -p aoclib --bench hash` compares the two.
+//!
+//! [`Vec2D`]: crate::Vec2D
+
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{BuildHasherDefault, Hasher},
+};
+
+/// A [`HashMap`] using [`FxHasher`], created with `FastMap::default()`.
+pub type FastMap<K, V> = HashMap<K, V, FxBuildHasher>;
+
+/// A [`HashSet`] using [`FxHasher`], created with `FastSet::default()`.
+pub type FastSet<T> = HashSet<T, FxBuildHasher>;
+
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
+/// An odd constant with well mixed bits, taken from rustc.
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+/// The FxHash hasher, see the [module documentation](self).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    #[inline]
+    fn add(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut words = bytes.chunks_exact(8);
+        for word in &mut words {
+            self.add(u64::from_le_bytes(word.try_into().unwrap()));
+        }
+        let rest = words.remainder();
+        if !rest.is_empty() {
+            let mut word = [0; 8];
+            word[..rest.len()].copy_from_slice(rest);
+            self.add(u64::from_le_bytes(word));
+        }
+    }
+
+    // integers skip the detour over bytes, the signed ones end up here too
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::{BuildHasher, Hash};
+
+    use super::*;
+    use crate::Vec2D;
+
+    fn hash(value: impl Hash) -> u64 {
+        FxBuildHasher::default().hash_one(value)
+    }
+
+    #[test]
+    fn hashes() {
+        // the same everywhere, unlike the randomly seeded SipHash
+        assert_eq!(hash(1u64), SEED);
+        assert_eq!(hash(Vec2D::new(3, 4)), hash(Vec2D::new(3, 4)));
+        assert_ne!(hash(Vec2D::new(3, 4)), hash(Vec2D::new(4, 3)));
+        assert_ne!(hash("abcdefghi"), hash("abcdefgh"));
+        assert_ne!(hash([0u8; 3].as_slice()), hash([0u8; 4].as_slice()));
+
+        let grid: FastSet<Vec2D<i32>> = (0..100)
+            .flat_map(|y| (0..100).map(move |x| Vec2D::new(x, y)))
+            .collect();
+        assert_eq!(grid.len(), 10_000);
+        let hashes: FastSet<u64> = grid.iter().map(hash).collect();
+        assert_eq!(hashes.len(), 10_000);
+    }
+
+    #[test]
+    fn collections() {
+        let mut map = FastMap::default();
+        *map.entry([1i64, -2, 3, 0]).or_insert(0) += 5;
+        *map.entry([1i64, -2, 3, 0]).or_insert(0) += 2;
+        assert_eq!(map[&[1, -2, 3, 0]], 7);
+        let set: FastSet<&str> = ["a", "b", "a"].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+}
@@ -1,134 +1,90 @@
 use std::{
     fmt::Display,
     fs::{self, File, OpenOptions},
-    io::Write,
-    ops::{Add, AddAssign, Mul, Rem},
+    io::{ErrorKind, Write},
+    path::Path,
 };
 
-pub fn input(name: &str) -> String {
-    // recreate empty output file
-    let _ = File::create("./output");
-    fs::read_to_string(format!("./{name}")).unwrap()
-}
-
-pub fn output(result: impl Display) {
-    println!("{result}");
-    let mut file = OpenOptions::new().append(true).open("./output").unwrap();
-    file.write_all(format!("{}\n", result).as_bytes()).unwrap();
-}
-
-/// A vector in Num^2.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Vec2D<T>
-where
-    T: Add<Output = T>,
-{
-    pub x: T,
-    pub y: T,
-}
-
-impl<T> Add for Vec2D<T>
-where
-    T: Add<Output = T>,
-{
-    type Output = Vec2D<T>;
-
-    fn add(self, rhs: Vec2D<T>) -> Self::Output {
-        Vec2D {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
-
-impl<T> AddAssign for Vec2D<T>
-where
-    T: Add<Output = T> + AddAssign,
-{
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-    }
-}
-
-impl<T, I> Mul<I> for Vec2D<T>
-where
-    T: Add<Output = T> + Mul<I, Output = T>,
-    I: Copy,
-{
-    type Output = Vec2D<T>;
-
-    /// Scalar multiplication.
-    fn mul(self, rhs: I) -> Self::Output {
-        Vec2D {
-            x: self.x * rhs,
-            y: self.y * rhs,
-        }
-    }
-}
-
-impl<T> Rem for Vec2D<T>
-where
-    T: Add<Output = T> + Rem<Output = T> + Copy,
-{
-    type Output = Vec2D<T>;
-
-    /// Calculates the elementwise modulus.
-    fn rem(self, rhs: Self) -> Self::Output {
-        Vec2D {
-            x: ((self.x % rhs.x) + rhs.x) % rhs.x,
-            y: ((self.y % rhs.y) + rhs.y) % rhs.y,
-        }
-    }
+pub mod bench;
+mod bitgrid;
+pub mod cycle;
+mod dir;
+mod disjoint_set;
+mod error;
+pub mod examples;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod graph;
+mod grid;
+pub mod hash;
+pub mod log;
+pub mod math;
+pub mod memo;
+pub mod par;
+pub mod params;
+pub mod parse;
+pub mod record;
+pub mod search;
+mod solution;
+mod sparse;
+#[cfg(feature = "fetch")]
+pub mod submit;
+mod vec2d;
+mod verify;
+pub mod viz;
+
+pub use bench::{Bench, Timings};
+pub use bitgrid::BitGrid;
+pub use dir::{Dir4, Dir8};
+pub use disjoint_set::DisjointSet;
+pub use error::{parse_field, Error, Result};
+pub use grid::Grid;
+pub use hash::{FastMap, FastSet};
+pub use solution::{solve, solve_part, Answer, Answers, Day, Solution};
+pub use sparse::SparseGrid;
+pub use vec2d::{
+    Vec2D, CARDINAL, DIAGONAL, EAST, NORTH, NORTH_EAST, NORTH_WEST, SOUTH, SOUTH_EAST, SOUTH_WEST,
+    WEST,
+};
+pub use verify::{KnownAnswers, Verdict};
+
+/// Reads the puzzle input at `path`.
+pub fn read_input(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        ErrorKind::NotFound => Error::MissingInput(path.to_path_buf()),
+        _ => Error::Io(e),
+    })
 }
 
-impl<T> Ord for Vec2D<T>
-where
-    T: Ord + Add<Output = T>,
-{
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.x.cmp(&other.x).then_with(|| self.y.cmp(&other.y))
-    }
+/// Reads the puzzle input `./{name}` and recreates an empty `./output` file.
+pub fn try_input(name: &str) -> Result<String> {
+    let input = read_input(format!("./{name}"))?;
+    // recreate empty output file
+    File::create("./output")?;
+    Ok(input)
 }
 
-impl<T> PartialOrd for Vec2D<T>
-where
-    T: Ord + Add<Output = T>,
-{
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
+/// Like [`try_input`] but exits with a readable message on failure.
+pub fn input(name: &str) -> String {
+    try_input(name).unwrap_or_else(|e| e.exit(""))
 }
 
-impl<T> Vec2D<T>
-where
-    T: Add<Output = T>,
-{
-    pub const fn new(x: T, y: T) -> Self {
-        Vec2D { x, y }
-    }
+/// Prints `result` and appends it to `./output`.
+///
+/// This is a plain line without day, part or timing. Only `aoc run`, which
+/// knows those, writes [`record::Record`]s to `dayN/output.jsonl`.
+pub fn try_output(result: impl Display) -> Result<()> {
+    println!("{result}");
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open("./output")?;
+    file.write_all(format!("{}\n", result).as_bytes())?;
+    Ok(())
 }
 
-impl<T> Vec2D<T>
-where
-    T: Add<Output = T> + PartialOrd,
-{
-    /// Work in progress...
-    pub fn bounded_add(self, rhs: Vec2D<T>, w: T, h: T) -> Option<Vec2D<T>> {
-        let nx = self.x + rhs.x;
-        let ny = self.y + rhs.y;
-        if nx < w && ny < h {
-            Some(Vec2D { x: nx, y: ny })
-        } else {
-            None
-        }
-    }
+/// Like [`try_output`] but exits with a readable message on failure.
+pub fn output(result: impl Display) {
+    try_output(result).unwrap_or_else(|e| e.exit(""))
 }
-
-// i32 types
-pub const NORTH: Vec2D<i32> = Vec2D::new(0, -1);
-pub const WEST: Vec2D<i32> = Vec2D::new(-1, 0);
-pub const SOUTH: Vec2D<i32> = Vec2D::new(0, 1);
-pub const EAST: Vec2D<i32> = Vec2D::new(1, 0);
-
-pub const CARDINAL: [Vec2D<i32>; 4] = [NORTH, WEST, SOUTH, EAST];
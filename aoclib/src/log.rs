@@ -0,0 +1,27 @@
+//! Debug output for `aoc run -v`.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+/// Turns the output of [`debug!`](crate::debug) on or off.
+pub fn set_verbose(verbose: bool) {
+    VERBOSE.store(verbose, Ordering::Relaxed);
+}
+
+/// Returns true if debug output is on.
+pub fn verbose() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+/// Prints a line to stderr like `eprintln!`, but only with `-v`.
+///
+/// The arguments are not evaluated otherwise.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::verbose() {
+            eprintln!("debug: {}", format_args!($($arg)*));
+        }
+    };
+}
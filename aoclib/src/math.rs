@@ -0,0 +1,292 @@
+//! Number theory on `i64`: gcd, modular inverses and the Chinese remainder theorem.
+//!
+//! Intermediate products are taken in `i128`, so moduli up to `i64::MAX` are fine.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// Returns the greatest common divisor of `a` and `b`, never negative.
+pub const fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Returns the least common multiple of `a` and `b`, never negative.
+pub const fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Returns (gcd, x, y) for ax+by=gcd(a,b).
+pub const fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+        (old_t, t) = (t, old_t - q * t);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+/// Returns x in `0..m` with ax = 1 (mod m), if `a` and `m` are coprime and
+/// `m` is positive.
+pub const fn mod_inv(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    if g == 1 {
+        Some(x.rem_euclid(m))
+    } else {
+        None
+    }
+}
+
+/// Returns a * b mod m in `0..m` without overflowing.
+pub const fn mul_mod(a: i64, b: i64, m: i64) -> i64 {
+    (a as i128 * b as i128).rem_euclid(m as i128) as i64
+}
+
+/// Returns base^exp mod m in `0..m` by repeated squaring.
+pub const fn pow_mod(base: i64, mut exp: u64, m: i64) -> i64 {
+    let mut base = base.rem_euclid(m);
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Solves the system x = a (mod m) for every `(a, m)` in `congruences`.
+///
+/// Returns `(x, lcm)` with `x` in `0..lcm` where `lcm` is the least common
+/// multiple of all moduli, so every solution is `x + k * lcm`. The moduli do
+/// not need to be coprime. Returns None if a modulus is not positive, the
+/// congruences contradict each other or the combined modulus does not fit
+/// into an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    congruences.iter().try_fold((0, 1), |(x, m), &(a, n)| {
+        if n <= 0 {
+            return None;
+        }
+        // x + m * k = a (mod n) for some k, solvable iff gcd(m, n) | a - x
+        let g = gcd(m, n);
+        let diff = a - x;
+        if diff % g != 0 {
+            return None;
+        }
+        let n_g = n / g;
+        let k = mul_mod(diff / g, mod_inv(m / g, n_g)?, n_g);
+        let lcm = m.checked_mul(n_g)?;
+        let x = (x as i128 + m as i128 * k as i128).rem_euclid(lcm as i128);
+        Some((x as i64, lcm))
+    })
+}
+
+/// An integer modulo `M`, always kept in `0..M`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModInt<const M: u64>(u64);
+
+impl<const M: u64> ModInt<M> {
+    /// Creates the residue of `value`, wrapping negative numbers around.
+    pub const fn new(value: i64) -> Self {
+        ModInt((value as i128).rem_euclid(M as i128) as u64)
+    }
+
+    /// Returns the value in `0..M`.
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Raises to the power `exp` by repeated squaring.
+    pub fn pow(self, mut exp: u64) -> Self {
+        let (mut base, mut result) = (self, ModInt::from(1u64));
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Returns the multiplicative inverse, if there is one.
+    pub fn inv(self) -> Option<Self> {
+        // extended euclid in i128, as M may not fit into an i64
+        let (mut old_r, mut r) = (M as i128, self.0 as i128);
+        let (mut old_t, mut t) = (0, 1);
+        while r != 0 {
+            let q = old_r / r;
+            (old_r, r) = (r, old_r - q * r);
+            (old_t, t) = (t, old_t - q * t);
+        }
+        (old_r == 1).then(|| ModInt(old_t.rem_euclid(M as i128) as u64))
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(value: u64) -> Self {
+        ModInt(value % M)
+    }
+}
+
+impl<const M: u64> From<i64> for ModInt<M> {
+    fn from(value: i64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const M: u64> Display for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        ModInt(((self.0 as u128 + rhs.0 as u128) % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        ModInt((M - self.0) % M)
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        ModInt(((self.0 as u128 * rhs.0 as u128) % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> Div for ModInt<M> {
+    type Output = Self;
+
+    /// Multiplies by the inverse of `rhs`.
+    ///
+    /// Panics if `rhs` is not coprime to `M`.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.mul(rhs.inv().expect("Division by a non-invertible ModInt."))
+    }
+}
+
+macro_rules! impl_assign {
+    ($($trait:ident $fn:ident $op:tt),+) => {
+        $(impl<const M: u64> $trait for ModInt<M> {
+            fn $fn(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        })+
+    };
+}
+
+impl_assign!(AddAssign add_assign +, SubAssign sub_assign -, MulAssign mul_assign *, DivAssign div_assign /);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+
+        let (g, x, y) = extended_gcd(101, 103);
+        assert_eq!(g, 1);
+        assert_eq!(101 * x + 103 * y, 1);
+        let (g, x, y) = extended_gcd(-12, 18);
+        assert_eq!((g, -12 * x + 18 * y), (6, 6));
+    }
+
+    #[test]
+    fn inverses() {
+        assert_eq!(mod_inv(3, 7), Some(5));
+        assert_eq!(mod_inv(-3, 7), Some(2));
+        assert_eq!(mod_inv(4, 8), None);
+        assert_eq!(mod_inv(3, 0), None);
+        assert_eq!(mod_inv(3, -7), None);
+        assert_eq!(mul_mod(i64::MAX - 1, i64::MAX - 1, i64::MAX), 1);
+        assert_eq!(pow_mod(2, 10, 1000), 24);
+        assert_eq!(pow_mod(-2, 3, 5), 2);
+        assert_eq!(pow_mod(5, 0, 1), 0);
+    }
+
+    #[test]
+    fn crt() {
+        assert_eq!(super::crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // the grid sizes of day 14
+        let (t, period) = super::crt(&[(12, 101), (7, 103)]).unwrap();
+        assert_eq!((t % 101, t % 103, period), (12, 7, 10403));
+        // moduli sharing factors
+        assert_eq!(super::crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(super::crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(super::crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(super::crt(&[]), Some((0, 1)));
+        assert_eq!(super::crt(&[(2, 3), (0, 0)]), None);
+        assert_eq!(super::crt(&[(1, -5)]), None);
+    }
+
+    #[test]
+    fn mod_int() {
+        type M7 = ModInt<7>;
+
+        assert_eq!(M7::new(-1).value(), 6);
+        assert_eq!(M7::from(10u64), M7::new(3));
+        assert_eq!(M7::new(5) + M7::new(4), M7::new(2));
+        assert_eq!(M7::new(2) - M7::new(5), M7::new(4));
+        assert_eq!(M7::new(3) * M7::new(5), M7::new(1));
+        assert_eq!(M7::new(1) / M7::new(3), M7::new(5));
+        assert_eq!(M7::new(3).pow(6), M7::new(1));
+        assert_eq!(-M7::new(0), M7::new(0));
+        assert_eq!(ModInt::<8>::new(4).inv(), None);
+
+        let mut x = M7::new(6);
+        x += M7::new(2);
+        x *= M7::new(3);
+        assert_eq!(x.to_string(), "3");
+
+        let big = ModInt::<{ u64::MAX - 58 }>::new(-1);
+        assert_eq!((big * big).value(), 1);
+        assert_eq!(big.inv(), Some(big));
+    }
+}
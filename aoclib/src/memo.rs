@@ -0,0 +1,180 @@
+//! Memoization of recursive functions.
+//!
+//! ```
+//! use aoclib::memo::Memo;
+//!
+//! fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+//!     if n < 2 {
+//!         return n;
+//!     }
+//!     memo.get_or_compute(&n, |memo| fib(n - 1, memo) + fib(n - 2, memo))
+//! }
+//!
+//! let mut memo = Memo::new();
+//! assert_eq!(fib(90, &mut memo), 2880067194370816120);
+//! assert_eq!(memo.stats().misses, 89);
+//! ```
+
+use std::{
+    borrow::Borrow,
+    fmt::{self, Display},
+    hash::Hash,
+};
+
+use crate::FastMap;
+
+/// A cache of computed values, with hit and miss counters.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: FastMap<K, V>,
+    limit: Option<usize>,
+    hits: u64,
+    misses: u64,
+}
+
+/// How well a [`Memo`] did so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Number of cached values.
+    pub len: usize,
+}
+
+impl Stats {
+    /// Returns the share of lookups that were hits, 0 without lookups.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hits), {} cached",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.len
+        )
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            cache: FastMap::default(),
+            limit: None,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Creates a memo caching at most `limit` values. Once it is full, new
+    /// values are computed but not cached anymore.
+    pub fn with_limit(limit: usize) -> Self {
+        Memo {
+            limit: Some(limit),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the cached value of `key`, or computes it with `f` and caches
+    /// it. `f` gets the memo for recursive calls.
+    ///
+    /// The key can be borrowed, like a `&str` for `String` keys, and is only
+    /// copied into the cache on a miss.
+    pub fn get_or_compute<Q>(&mut self, key: &Q, f: impl FnOnce(&mut Self) -> V) -> V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(value) = self.cache.get(key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+
+        let value = f(self);
+        if self.limit.is_none_or(|limit| self.cache.len() < limit) {
+            self.cache.insert(key.to_owned(), value.clone());
+        }
+        value
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats {
+            hits: self.hits,
+            misses: self.misses,
+            len: self.cache.len(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Forgets all values, the statistics are kept.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts the ways to split `s` into the words "a", "ab" and "b".
+    fn splits(s: &str, memo: &mut Memo<String, u64>) -> u64 {
+        if s.is_empty() {
+            return 1;
+        }
+        memo.get_or_compute(s, |memo| {
+            ["a", "ab", "b"]
+                .iter()
+                .filter_map(|w| s.strip_prefix(w))
+                .map(|rest| splits(rest, memo))
+                .sum()
+        })
+    }
+
+    #[test]
+    fn borrowed_keys() {
+        let mut memo = Memo::new();
+        assert_eq!(splits("abab", &mut memo), 4);
+        assert_eq!(memo.len(), 4);
+        assert_eq!(splits("abab", &mut memo), 4);
+        let stats = memo.stats();
+        assert_eq!((stats.hits, stats.misses), (2, 4));
+        assert_eq!(stats.to_string(), "2 hits, 4 misses (33.3% hits), 4 cached");
+    }
+
+    #[test]
+    fn limit() {
+        let mut memo = Memo::with_limit(2);
+        for key in 0..5 {
+            assert_eq!(memo.get_or_compute(&key, |_| key * 2), key * 2);
+        }
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.get_or_compute(&4, |_| 0), 0);
+        assert_eq!(memo.get_or_compute(&1, |_| 0), 2);
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats().hit_rate(), 1.0 / 7.0);
+    }
+}
@@ -0,0 +1,191 @@
+//! Data parallelism for brute force searches, on scoped threads of the
+//! standard library.
+//!
+//! The items are cut into a few chunks per thread, which the threads take
+//! one after another, so uneven work still keeps every core busy. A panic
+//! in `f` is passed on to the caller once all threads stopped.
+
+use std::{
+    iter::Sum,
+    ops::Range,
+    panic, slice,
+    sync::{Mutex, OnceLock},
+    thread,
+};
+
+/// Chunks per thread, more chunks balance uneven work better.
+const CHUNKS_PER_THREAD: usize = 4;
+
+/// Items that can be cut into chunks for the threads, implemented for
+/// slices and ranges of integers.
+pub trait Split: Send + Sized {
+    type Item;
+    type Iter: Iterator<Item = Self::Item>;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Splits into the first `mid` items and the rest.
+    fn split_at(self, mid: usize) -> (Self, Self);
+
+    fn into_iter(self) -> Self::Iter;
+}
+
+impl<'a, T: Sync> Split for &'a [T] {
+    type Item = &'a T;
+    type Iter = slice::Iter<'a, T>;
+
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn split_at(self, mid: usize) -> (Self, Self) {
+        <[T]>::split_at(self, mid)
+    }
+
+    fn into_iter(self) -> Self::Iter {
+        self.iter()
+    }
+}
+
+macro_rules! impl_split_range {
+    ($($t:ty),+) => {
+        $(impl Split for Range<$t> {
+            type Item = $t;
+            type Iter = Range<$t>;
+
+            fn len(&self) -> usize {
+                ExactSizeIterator::len(self)
+            }
+
+            fn split_at(self, mid: usize) -> (Self, Self) {
+                let mid = self.start + mid as $t;
+                (self.start..mid, mid..self.end)
+            }
+
+            fn into_iter(self) -> Self::Iter {
+                self
+            }
+        })+
+    };
+}
+
+impl_split_range!(usize, u32, i32, u16, i16, u8, i8);
+
+/// Returns the number of threads used, one per available core.
+pub fn threads() -> usize {
+    static THREADS: OnceLock<usize> = OnceLock::new();
+    *THREADS.get_or_init(|| thread::available_parallelism().map_or(1, |n| n.get()))
+}
+
+/// Cuts `items` into chunks of equal size, the last one may be smaller.
+fn chunks<S: Split>(items: S, size: usize) -> Vec<S> {
+    let mut chunks = Vec::new();
+    let mut rest = items;
+    while rest.len() > size {
+        let (chunk, after) = rest.split_at(size);
+        chunks.push(chunk);
+        rest = after;
+    }
+    chunks.push(rest);
+    chunks
+}
+
+/// Runs `f` on chunks of `items` on all threads and returns the results of
+/// the chunks in order.
+fn run<S, R>(items: S, f: impl Fn(S::Iter) -> R + Sync) -> Vec<R>
+where
+    S: Split,
+    R: Send,
+{
+    let len = items.len();
+    let threads = threads().min(len);
+    if threads <= 1 {
+        return vec![f(items.into_iter())];
+    }
+
+    let size = len.div_ceil(threads * CHUNKS_PER_THREAD);
+    let queue = Mutex::new(chunks(items, size).into_iter().enumerate());
+    let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    // the lock is released before working on the chunk
+                    while let Some((i, chunk)) = queue.lock().ok().and_then(|mut q| q.next()) {
+                        done.push((i, f(chunk.into_iter())));
+                    }
+                    done
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    });
+    results.sort_unstable_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+/// Maps every item with `f` in parallel, keeping the order of `items`.
+pub fn par_map<S, R>(items: S, f: impl Fn(S::Item) -> R + Sync) -> Vec<R>
+where
+    S: Split,
+    R: Send,
+{
+    run(items, |chunk| chunk.map(&f).collect::<Vec<_>>())
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Counts the items `f` is true for, in parallel.
+pub fn par_filter_count<S: Split>(items: S, f: impl Fn(S::Item) -> bool + Sync) -> usize {
+    run(items, |chunk| chunk.map(&f).filter(|&b| b).count())
+        .into_iter()
+        .sum()
+}
+
+/// Sums `f` over all items, in parallel.
+pub fn par_sum<S, R>(items: S, f: impl Fn(S::Item) -> R + Sync) -> R
+where
+    S: Split,
+    R: Sum + Send,
+{
+    run(items, |chunk| chunk.map(&f).sum::<R>())
+        .into_iter()
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_order() {
+        let squares = par_map(0..1000usize, |i| i * i);
+        assert_eq!(squares, (0..1000).map(|i| i * i).collect::<Vec<_>>());
+        let words = ["a", "bb", "ccc"];
+        assert_eq!(par_map(&words[..], |w| w.len()), [1, 2, 3]);
+        assert!(par_map(0..0u32, |i| i).is_empty());
+    }
+
+    #[test]
+    fn reductions() {
+        assert_eq!(par_filter_count(1..100i32, |i| i % 3 == 0), 33);
+        assert_eq!(par_sum(0..0u8, |i| i as u32), 0);
+        let values: Vec<u64> = (1..=1000).collect();
+        assert_eq!(par_sum(&values[..], |&v| v), 500500);
+        assert_eq!(par_filter_count(&values[..0], |_| true), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "bad item")]
+    fn passes_panics_on() {
+        par_map(0..100usize, |i| assert!(i != 42, "bad item"));
+    }
+}
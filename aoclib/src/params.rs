@@ -0,0 +1,141 @@
+//! Puzzle parameters, like grid sizes that differ between the examples and
+//! the real input.
+//!
+//! They are set with `aoc run --param KEY=VALUE` or by `param.KEY = value` in
+//! `examples.toml`. Days read them with [`get`], falling back to the value of
+//! the real input.
+//!
+//! The parameters are shared by the whole process. Tests, which run in
+//! parallel, must set them through [`with`] only.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    str::FromStr,
+    sync::{Mutex, RwLock},
+};
+
+use crate::{Error, Result};
+
+static PARAMS: RwLock<BTreeMap<String, String>> = RwLock::new(BTreeMap::new());
+
+/// Held by [`with`] while its parameters are set.
+static EXCLUSIVE: Mutex<()> = Mutex::new(());
+
+/// Sets the parameter `key` to `value`.
+///
+/// Not for tests, as the parameters of a concurrent test would change too.
+pub fn set(key: impl Into<String>, value: impl Into<String>) {
+    PARAMS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(key.into(), value.into());
+}
+
+/// Removes all parameters.
+///
+/// Not for tests, as the parameters of a concurrent test would vanish too.
+pub fn clear() {
+    PARAMS.write().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// Clears the parameters when [`with`] is done, even if `f` panics.
+struct Reset;
+
+impl Drop for Reset {
+    fn drop(&mut self) {
+        clear();
+    }
+}
+
+/// Runs `f` with exactly `params` set, waiting for other calls to finish
+/// first. All parameters are cleared afterwards.
+pub fn with<K, V, R>(params: impl IntoIterator<Item = (K, V)>, f: impl FnOnce() -> R) -> R
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    // a panic while holding the lock leaves nothing broken behind
+    let _lock = EXCLUSIVE.lock().unwrap_or_else(|e| e.into_inner());
+    let _reset = Reset;
+    clear();
+    for (key, value) in params {
+        set(key, value);
+    }
+    f()
+}
+
+/// Returns the parameter `key`, or `default` if it is not set. Fails if the
+/// value does not parse as `T`.
+pub fn try_get<T>(key: &str, default: T) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let params = PARAMS.read().unwrap_or_else(|e| e.into_inner());
+    match params.get(key) {
+        Some(value) => value.parse().map_err(|e: T::Err| Error::Param {
+            key: key.to_string(),
+            value: value.clone(),
+            msg: e.to_string(),
+        }),
+        None => Ok(default),
+    }
+}
+
+/// Like [`try_get`] but exits with a readable message on failure.
+pub fn get<T>(key: &str, default: T) -> T
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    try_get(key, default).unwrap_or_else(|e| e.exit(""))
+}
+
+/// Parses a `KEY=VALUE` pair.
+pub fn parse_pair(pair: &str) -> Option<(&str, &str)> {
+    let (key, value) = pair.split_once('=')?;
+    let key = key.trim();
+    (!key.is_empty()).then_some((key, value.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn params() {
+        with([("params-test", "11")], || {
+            assert_eq!(get("params-test", 101), 11);
+            assert_eq!(get("params-test", 1.5), 11.0);
+            assert_eq!(get("other", 7), 7);
+        });
+        with::<&str, &str, _>([], || assert_eq!(get("params-test", 101), 101));
+    }
+
+    #[test]
+    fn invalid() {
+        with([("width", "wide")], || {
+            let e = try_get("width", 101u32).unwrap_err();
+            assert_eq!(
+                e.to_string(),
+                "invalid value `wide` for parameter `width`: invalid digit found in string"
+            );
+        });
+    }
+
+    #[test]
+    fn cleared_after_panic() {
+        let panicked = std::panic::catch_unwind(|| with([("width", "5")], || panic!("oops")));
+        assert!(panicked.is_err());
+        let _lock = EXCLUSIVE.lock().unwrap_or_else(|e| e.into_inner());
+        assert!(PARAMS.read().unwrap_or_else(|e| e.into_inner()).is_empty());
+    }
+
+    #[test]
+    fn pairs() {
+        assert_eq!(parse_pair("width = 11"), Some(("width", "11")));
+        assert_eq!(parse_pair("=11"), None);
+        assert_eq!(parse_pair("width"), None);
+    }
+}
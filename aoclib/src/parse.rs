@@ -0,0 +1,300 @@
+//! Helpers for taking puzzle input apart.
+//!
+//! Errors point at the offending spot of the text they were given. Use
+//! [`map_lines`] and [`map_blocks`], or [`Error::within`], to report them
+//! relative to the whole input.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{parse_field, Error, Grid, Result};
+
+/// Extracts every integer in `s`, skipping whatever lies between them.
+///
+/// A `-` directly in front of a number is its sign if `T` can be negative
+/// and the `-` does not follow another number, so `x=-3` holds -3 but the
+/// range `1-3` holds 1 and 3.
+pub fn ints<T>(s: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let signed = "-1".parse::<T>().is_ok();
+    let bytes = s.as_bytes();
+    let mut ints = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let mut start = i;
+        if signed && i > 0 && bytes[i - 1] == b'-' && (i < 2 || !bytes[i - 2].is_ascii_digit()) {
+            start -= 1;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        ints.push(parse_field(s, &s[start..i])?);
+    }
+    Ok(ints)
+}
+
+/// Returns the sections of `input` separated by blank lines.
+///
+/// Leading and trailing blank lines are dropped, so are the line breaks at
+/// the end of every block. Blocks are slices of `input`.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input;
+    std::iter::from_fn(move || {
+        // skip blank lines in front of the block
+        while let Some((line, after)) = rest.split_once('\n') {
+            if !line.trim().is_empty() {
+                break;
+            }
+            rest = after;
+        }
+        if rest.trim().is_empty() {
+            return None;
+        }
+
+        let mut end = 0;
+        let mut lines = rest.split_inclusive('\n');
+        for line in lines.by_ref() {
+            if line.trim().is_empty() {
+                break;
+            }
+            end += line.len();
+        }
+        let block = rest[..end].trim_end_matches(['\n', '\r']);
+        rest = &rest[end..];
+        Some(block)
+    })
+}
+
+/// Splits `input` into exactly `N` blocks separated by blank lines.
+pub fn split_blocks<const N: usize>(input: &str) -> Result<[&str; N]> {
+    let blocks: Vec<_> = blocks(input).collect();
+    let found = blocks.len();
+    blocks.try_into().map_err(|_| {
+        let end = &input[input.trim_end().len()..];
+        Error::at(
+            input,
+            end,
+            format!("expected {N} blocks separated by blank lines, found {found}"),
+        )
+    })
+}
+
+/// Parses every line of `input` with `f`.
+///
+/// Errors of `f` point into the line it was given and are moved to their
+/// position in `input`.
+pub fn map_lines<T>(input: &str, mut f: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    input
+        .lines()
+        .map(|line| f(line).map_err(|e| e.within(input, line)))
+        .collect()
+}
+
+/// Parses every block of `input` with `f`, see [`blocks`].
+///
+/// Errors of `f` point into the block it was given and are moved to their
+/// position in `input`.
+pub fn map_blocks<T>(input: &str, mut f: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    blocks(input)
+        .map(|block| f(block).map_err(|e| e.within(input, block)))
+        .collect()
+}
+
+/// Parses `input` into a grid of characters, failing on ragged lines.
+pub fn grid_chars(input: &str) -> Result<Grid<char>> {
+    Grid::try_parse_with(input, Some)
+}
+
+/// Matches `s` against `pattern` and returns the text of every `{}` in it.
+///
+/// Everything outside of the `{}` has to match exactly. A field ends where
+/// the text following it in the pattern starts, or at the end of `s`. Only
+/// the last field may span several lines.
+pub fn fields<'a>(pattern: &str, s: &'a str) -> Result<Vec<&'a str>> {
+    let mut literals = pattern.split("{}");
+    let first = literals.next().unwrap_or_default();
+    let mut rest = s
+        .strip_prefix(first)
+        .ok_or_else(|| Error::at(s, s, format!("expected `{}`", first.escape_debug())))?;
+
+    let mut fields = Vec::new();
+    for literal in literals {
+        let end = match literal {
+            "" => rest.len(),
+            _ => {
+                let line_end = rest.find('\n').unwrap_or(rest.len());
+                rest.find(literal)
+                    .filter(|&end| end <= line_end)
+                    .ok_or_else(|| {
+                        let msg = format!("expected `{}`", literal.escape_debug());
+                        Error::at(s, rest, msg)
+                    })?
+            }
+        };
+        fields.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+    if !rest.is_empty() {
+        return Err(Error::at(
+            s,
+            rest,
+            format!("unexpected `{}`", rest.escape_debug()),
+        ));
+    }
+    Ok(fields)
+}
+
+/// Values that can be parsed from the fields of a [`scan!`](crate::scan) pattern.
+///
+/// Implemented for tuples of up to eight [`FromStr`] values.
+pub trait Scan: Sized {
+    /// Parses `fields`, which are slices of `s`.
+    ///
+    /// Panics if the number of fields does not match.
+    fn from_fields(s: &str, fields: &[&str]) -> Result<Self>;
+}
+
+macro_rules! impl_scan {
+    ($n:literal: $($t:ident),+) => {
+        impl<$($t),+> Scan for ($($t,)+)
+        where
+            $($t: FromStr, $t::Err: Display,)+
+        {
+            fn from_fields(s: &str, fields: &[&str]) -> Result<Self> {
+                assert_eq!(fields.len(), $n, "Pattern and types of scan! differ in length.");
+                let mut fields = fields.iter();
+                Ok(($(parse_field::<$t>(s, fields.next().unwrap())?,)+))
+            }
+        }
+    };
+}
+
+impl_scan!(1: A);
+impl_scan!(2: A, B);
+impl_scan!(3: A, B, C);
+impl_scan!(4: A, B, C, D);
+impl_scan!(5: A, B, C, D, E);
+impl_scan!(6: A, B, C, D, E, F);
+impl_scan!(7: A, B, C, D, E, F, G);
+impl_scan!(8: A, B, C, D, E, F, G, H);
+
+/// Matches `s` against `pattern` and parses its fields, see [`fields`].
+pub fn scan<T: Scan>(pattern: &str, s: &str) -> Result<T> {
+    T::from_fields(s, &fields(pattern, s)?)
+}
+
+/// Parses the `{}` fields of a pattern into the listed types.
+///
+/// ```
+/// let (x, y) = aoclib::scan!("Button A: X+94, Y+34", "Button A: X+{}, Y+{}" => i64, i64)?;
+/// assert_eq!((x, y), (94, 34));
+/// # Ok::<(), aoclib::Error>(())
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($s:expr, $pattern:expr => $t:ty) => {
+        $crate::parse::scan::<($t,)>($pattern, $s).map(|(v,)| v)
+    };
+    ($s:expr, $pattern:expr => $($t:ty),+ $(,)?) => {
+        $crate::parse::scan::<($($t,)+)>($pattern, $s)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ints() {
+        assert_eq!(super::ints::<i32>("p=0,4 v=3,-3").unwrap(), [0, 4, 3, -3]);
+        assert_eq!(super::ints::<i32>("1-3 -7").unwrap(), [1, 3, -7]);
+        assert_eq!(super::ints::<u32>("x=-3, y=12").unwrap(), [3, 12]);
+        assert!(super::ints::<u32>("").unwrap().is_empty());
+
+        let err = super::ints::<u8>("1\n2 300").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn blocks() {
+        let input = "\na\nb\n\n\n c\n\nd\r\n\r\n";
+        assert_eq!(
+            super::blocks(input).collect::<Vec<_>>(),
+            ["a\nb", " c", "d"]
+        );
+        assert_eq!(super::blocks("").count(), 0);
+
+        let [a, b] = split_blocks("1\n\n2\n").unwrap();
+        assert_eq!((a, b), ("1", "2"));
+        assert!(split_blocks::<2>("1\n2\n").is_err());
+    }
+
+    #[test]
+    fn errors_are_moved_into_the_input() {
+        let input = "1 2\n3 x\n\n4\nx";
+        let err = map_lines(input, |l| match l {
+            "3 x" => Err(Error::at(l, &l[2..], "bad")),
+            _ => Ok(()),
+        })
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            }
+        ));
+
+        let err = map_blocks(input, |b| match b.strip_prefix("4\n") {
+            Some(x) => Err(Error::at(b, x, "bad")),
+            None => Ok(()),
+        })
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Parse {
+                line: 5,
+                column: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn scan() {
+        let (x, y) = crate::scan!("Prize: X=8400, Y=-5", "Prize: X={}, Y={}" => u32, i32).unwrap();
+        assert_eq!((x, y), (8400, -5));
+        let r: u8 = crate::scan!("#12", "#{}" => u8).unwrap();
+        assert_eq!(r, 12);
+
+        let err = crate::scan!("Prize: X=1; Y=2", "Prize: X={}, Y={}" => u32, u32).unwrap_err();
+        assert!(matches!(err, Error::Parse { column: 10, .. }));
+        let err = crate::scan!("a=1 b", "a={}" => u32).unwrap_err();
+        assert!(matches!(err, Error::Parse { column: 3, .. }));
+        assert!(fields("{} and {}", "x and y and z").unwrap() == ["x", "y and z"]);
+        // fields end at the line break
+        let err = fields("a={}, b={}\n", "a=1\nc=2, b=3\n").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Parse {
+                line: 1,
+                column: 3,
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,107 @@
+//! Machine readable results, written as one JSON object per line:
+//!
+//! ```json
+//! {"day":3,"part":1,"answer":"161","duration_ns":41250,"input_hash":"6b1c0e5a3f2d9e47"}
+//! ```
+//!
+//! The input hash tells apart answers to different inputs, like an example
+//! passed with `aoc run --input`. The runner writes these records, the
+//! untagged [`output`](crate::output) of a standalone day does not.
+
+use std::{fmt::Write as _, fs::OpenOptions, io::Write, path::Path, time::Duration};
+
+use crate::Result;
+
+/// The answer to a part of a day, solved for a certain input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// Time spent solving the part, without parsing.
+    pub duration: Duration,
+    /// The [`hash_input`] of the input.
+    pub input_hash: u64,
+}
+
+impl Record {
+    /// Returns the record as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"day":{},"part":{},"answer":{},"duration_ns":{},"input_hash":"{:016x}"}}"#,
+            self.day,
+            self.part,
+            json_string(&self.answer),
+            self.duration.as_nanos(),
+            self.input_hash
+        )
+    }
+}
+
+/// Returns the 64 bit FNV-1a hash of `input`, which does not change between
+/// runs or Rust versions.
+pub fn hash_input(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Quotes `s` as a JSON string.
+pub(crate) fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Appends `records` to the file at `path`, one per line.
+pub fn append(path: impl AsRef<Path>, records: &[Record]) -> Result<()> {
+    let lines: String = records.iter().map(|r| r.to_json() + "\n").collect();
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(lines.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json() {
+        let record = Record {
+            day: 17,
+            part: 1,
+            answer: "4,6,3,\"5\"\n".to_string(),
+            duration: Duration::from_micros(12),
+            input_hash: 0xab,
+        };
+        assert_eq!(
+            record.to_json(),
+            r#"{"day":17,"part":1,"answer":"4,6,3,\"5\"\n","duration_ns":12000,"input_hash":"00000000000000ab"}"#
+        );
+        assert_eq!(json_string("a\\b\u{1}"), r#""a\\b\u0001""#);
+    }
+
+    #[test]
+    fn hash() {
+        assert_eq!(hash_input(""), 0xcbf29ce484222325);
+        assert_eq!(hash_input("a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(hash_input("1\n2\n"), hash_input("2\n1\n"));
+    }
+}
@@ -0,0 +1,306 @@
+//! Shortest path searches over implicit graphs.
+//!
+//! States can be anything hashable, edges are produced on demand by a neighbour closure.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+};
+
+use crate::{FastMap, FastSet};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Node<S> {
+    cost: usize,
+    state: S,
+}
+
+impl<S: Eq> Ord for Node<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Flip ordering of costs to make a min-heap
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl<S: Eq> PartialOrd for Node<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Distances and parents of every state reached by a search.
+#[derive(Debug, Clone)]
+pub struct Paths<S> {
+    start: S,
+    dist: FastMap<S, usize>,
+    /// All predecessors on a shortest path, in discovery order.
+    parents: FastMap<S, Vec<S>>,
+}
+
+impl<S> Paths<S>
+where
+    S: Clone + Eq + Hash,
+{
+    fn new(start: S) -> Self {
+        Paths {
+            dist: FastMap::from_iter([(start.clone(), 0)]),
+            start,
+            parents: FastMap::default(),
+        }
+    }
+
+    /// Records `cost` to reach `next` from `from`. Returns true if `next` improved.
+    fn relax(&mut self, from: &S, next: S, cost: usize) -> bool {
+        if next == self.start {
+            return false;
+        }
+        match self.dist.get(&next) {
+            Some(&d) if cost > d => false,
+            Some(&d) if cost == d => {
+                let ps = self.parents.entry(next).or_default();
+                if !ps.contains(from) {
+                    ps.push(from.clone());
+                }
+                false
+            }
+            _ => {
+                self.dist.insert(next.clone(), cost);
+                self.parents.insert(next, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    /// Returns the cost of the cheapest path to `state`.
+    pub fn cost(&self, state: &S) -> Option<usize> {
+        self.dist.get(state).copied()
+    }
+
+    /// Returns all reached states and their costs.
+    pub fn distances(&self) -> &FastMap<S, usize> {
+        &self.dist
+    }
+
+    /// Returns the first discovered predecessor of `state` on a cheapest path.
+    pub fn parent(&self, state: &S) -> Option<&S> {
+        self.parents(state).first()
+    }
+
+    /// Returns all predecessors of `state` that lie on a cheapest path.
+    pub fn parents(&self, state: &S) -> &[S] {
+        self.parents.get(state).map_or(&[], |ps| ps.as_slice())
+    }
+
+    /// Reconstructs one cheapest path from the start to `goal`, both included.
+    pub fn path_to(&self, goal: &S) -> Option<Vec<S>> {
+        self.dist.get(goal)?;
+        let mut path = vec![goal.clone()];
+        while let Some(parent) = self.parent(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Returns every state on any cheapest path from the start to one of `goals`.
+    pub fn on_any_path(&self, goals: impl IntoIterator<Item = S>) -> FastSet<S> {
+        let mut seen = FastSet::default();
+        let mut stack: Vec<S> = goals
+            .into_iter()
+            .filter(|g| self.dist.contains_key(g))
+            .collect();
+        while let Some(s) = stack.pop() {
+            if seen.insert(s.clone()) {
+                stack.extend(self.parents(&s).iter().cloned());
+            }
+        }
+        seen
+    }
+}
+
+/// Runs dijkstra from `start`. `neighbours` yields every successor state with its edge cost.
+pub fn dijkstra<S, F, I>(start: S, mut neighbours: F) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut paths = Paths::new(start.clone());
+    let mut heap = BinaryHeap::new();
+    heap.push(Node {
+        cost: 0,
+        state: start,
+    });
+
+    while let Some(Node { cost, state }) = heap.pop() {
+        if cost > paths.dist[&state] {
+            // outdated entry
+            continue;
+        }
+        for (next, w) in neighbours(&state) {
+            if paths.relax(&state, next.clone(), cost + w) {
+                heap.push(Node {
+                    cost: cost + w,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    paths
+}
+
+/// Runs a breadth first search from `start`, every edge costs 1.
+pub fn bfs<S, F, I>(start: S, mut neighbours: F) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::new(start.clone());
+    let mut q = VecDeque::from([start]);
+
+    while let Some(state) = q.pop_front() {
+        let cost = paths.dist[&state] + 1;
+        for next in neighbours(&state) {
+            if paths.relax(&state, next.clone(), cost) {
+                q.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Runs A* from `start` until `is_goal` holds. Returns the path and its cost.
+///
+/// `heuristic` must never overestimate the remaining cost or the result is not optimal.
+pub fn astar<S, F, I, H, G>(
+    start: S,
+    mut neighbours: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<S>, usize)>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, usize)>,
+    H: FnMut(&S) -> usize,
+    G: FnMut(&S) -> bool,
+{
+    let mut paths = Paths::new(start.clone());
+    let mut heap = BinaryHeap::new();
+    heap.push(Node {
+        cost: heuristic(&start),
+        state: start,
+    });
+
+    while let Some(Node { cost: f, state }) = heap.pop() {
+        let cost = paths.dist[&state];
+        if f > cost + heuristic(&state) {
+            // outdated entry
+            continue;
+        }
+        if is_goal(&state) {
+            return Some((paths.path_to(&state)?, cost));
+        }
+        for (next, w) in neighbours(&state) {
+            if paths.relax(&state, next.clone(), cost + w) {
+                heap.push(Node {
+                    cost: cost + w + heuristic(&next),
+                    state: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Vec2D};
+
+    /// Two routes 0-1-3-4 and 0-2-3-4 of cost 3, a dearer direct edge 0-4,
+    /// a dead end 5 and the unreachable state 6.
+    fn edges(s: &u8) -> Vec<(u8, usize)> {
+        match s {
+            0 => vec![(1, 1), (2, 1), (4, 4), (5, 2)],
+            1 => vec![(3, 1)],
+            2 => vec![(3, 1)],
+            3 => vec![(4, 1)],
+            6 => vec![(0, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn tied_paths() {
+        let paths = dijkstra(0u8, edges);
+        assert_eq!(paths.cost(&4), Some(3));
+        assert_eq!(paths.parents(&3), [1, 2]);
+        assert_eq!(paths.parents(&4), [3]);
+        assert_eq!(paths.parents(&0), []);
+        assert_eq!(paths.path_to(&4), Some(vec![0, 1, 3, 4]));
+
+        let mut on_path: Vec<u8> = paths.on_any_path([4]).into_iter().collect();
+        on_path.sort();
+        assert_eq!(on_path, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn unreachable() {
+        let paths = dijkstra(0u8, edges);
+        assert_eq!(paths.cost(&6), None);
+        assert_eq!(paths.path_to(&6), None);
+        assert!(paths.on_any_path([6]).is_empty());
+        assert_eq!(paths.distances().len(), 6);
+    }
+
+    #[test]
+    fn relax() {
+        let mut paths = Paths::new(0u8);
+        assert!(paths.relax(&0, 1, 5));
+        assert!(!paths.relax(&2, 1, 6));
+        assert!(!paths.relax(&3, 1, 5));
+        assert!(!paths.relax(&3, 1, 5));
+        assert_eq!(paths.parents(&1), [0, 3]);
+        assert!(paths.relax(&4, 1, 2));
+        assert_eq!(paths.parents(&1), [4]);
+        assert!(!paths.relax(&1, 0, 1));
+    }
+
+    fn open(grid: &Grid<char>, pos: Vec2D<i32>) -> impl Iterator<Item = Vec2D<i32>> + '_ {
+        grid.neighbours(pos).filter(|&n| grid[n] != '#')
+    }
+
+    #[test]
+    fn bfs_grid() {
+        let grid = Grid::parse("...\n.#.\n...");
+        let paths = bfs(Vec2D::new(0, 0), |&p| open(&grid, p).collect::<Vec<_>>());
+        assert_eq!(paths.cost(&Vec2D::new(2, 2)), Some(4));
+        assert_eq!(paths.cost(&Vec2D::new(0, 1)), Some(1));
+        assert_eq!(paths.cost(&Vec2D::new(1, 1)), None);
+        assert_eq!(paths.cost(&Vec2D::new(3, 0)), None);
+        assert_eq!(paths.parents(&Vec2D::new(2, 2)).len(), 2);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let grid = Grid::parse(".....\n.###.\n...#.\n##.#.\n.....");
+        let goal = Vec2D::new(0, 4);
+        let weighted = |&p: &Vec2D<i32>| open(&grid, p).map(|n| (n, 1)).collect::<Vec<_>>();
+        let manhattan = |p: &Vec2D<i32>| p.manhattan(goal) as usize;
+
+        let (path, cost) = astar(Vec2D::new(0, 0), weighted, manhattan, |&p| p == goal).unwrap();
+        assert_eq!(Some(cost), dijkstra(Vec2D::new(0, 0), weighted).cost(&goal));
+        assert_eq!(cost, 8);
+        assert_eq!(path.len(), cost + 1);
+        assert_eq!((path[0], path[cost]), (Vec2D::new(0, 0), goal));
+
+        let blocked = Vec2D::new(2, 1);
+        assert_eq!(astar(goal, weighted, manhattan, |&p| p == blocked), None);
+    }
+}
@@ -0,0 +1,93 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    bench::{bench, Bench, Timings},
+    Result,
+};
+
+/// A solution for a single puzzle day.
+pub trait Solution {
+    /// Number of the puzzle day.
+    const DAY: u8;
+
+    /// The preprocessed puzzle input shared by both parts.
+    type Input;
+
+    /// Preprocesses the raw puzzle input.
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> impl Display;
+
+    fn part2(input: &Self::Input) -> impl Display;
+}
+
+/// The answer to a part and how long solving it took, without parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub value: String,
+    pub time: Duration,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+/// The answers of both parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Answer,
+    pub part2: Answer,
+}
+
+/// Runs `part` and times it, including turning its answer into a string.
+fn timed<T: Display>(part: impl FnOnce() -> T) -> Answer {
+    let start = Instant::now();
+    let value = part().to_string();
+    Answer {
+        value,
+        time: start.elapsed(),
+    }
+}
+
+/// Parses `input` and solves both parts of `S`.
+pub fn solve<S: Solution>(input: &str) -> Result<Answers> {
+    let input = S::parse(input)?;
+    let part1 = timed(|| S::part1(&input));
+    let part2 = timed(|| S::part2(&input));
+    Ok(Answers { part1, part2 })
+}
+
+/// Parses `input` and solves only `part` of `S`, which has to be 1 or 2.
+pub fn solve_part<S: Solution>(input: &str, part: u8) -> Result<Answer> {
+    let input = S::parse(input)?;
+    Ok(match part {
+        1 => timed(|| S::part1(&input)),
+        2 => timed(|| S::part2(&input)),
+        _ => panic!("Part has to be 1 or 2."),
+    })
+}
+
+/// A type erased [`Solution`], so days can be stored in a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub solve: fn(&str) -> Result<Answers>,
+    pub solve_part: fn(&str, u8) -> Result<Answer>,
+    pub bench: fn(&str, Bench) -> Result<Timings>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Day {
+            day: S::DAY,
+            solve: solve::<S>,
+            solve_part: solve_part::<S>,
+            bench: bench::<S>,
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::ops::Index;
+
+use crate::{FastMap, Grid, Vec2D};
+
+/// An unbounded grid that only stores the cells that were set.
+///
+/// Positions can be negative or grow as far as the puzzle needs. All other
+/// cells hold the default value. The bounding box of the set cells is kept
+/// up to date, so the occupied area can be cropped into a [`Grid`] or
+/// rendered.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: FastMap<Vec2D<i32>, T>,
+    default: T,
+    /// The smallest and largest corner of the set cells, if any.
+    bounds: Option<(Vec2D<i32>, Vec2D<i32>)>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates an empty grid reading `default` everywhere.
+    pub fn new(default: T) -> Self {
+        SparseGrid {
+            cells: FastMap::default(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Returns the number of set cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the value at `pos`, the default if it was not set.
+    pub fn get(&self, pos: Vec2D<i32>) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.default)
+    }
+
+    /// Returns true if the cell at `pos` was set.
+    pub fn contains(&self, pos: Vec2D<i32>) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Sets the cell at `pos` to `value`, returning the previous value if it
+    /// was set.
+    pub fn set(&mut self, pos: Vec2D<i32>, value: T) -> Option<T> {
+        self.grow(pos);
+        self.cells.insert(pos, value)
+    }
+
+    /// Returns the value at `pos` for changing it. An unset cell is set to
+    /// the default first.
+    pub fn get_mut(&mut self, pos: Vec2D<i32>) -> &mut T
+    where
+        T: Clone,
+    {
+        self.grow(pos);
+        self.cells
+            .entry(pos)
+            .or_insert_with(|| self.default.clone())
+    }
+
+    /// Unsets the cell at `pos` and returns its value.
+    pub fn remove(&mut self, pos: Vec2D<i32>) -> Option<T> {
+        let value = self.cells.remove(&pos)?;
+        // only cells on the border of the box can shrink it
+        if let Some((min, max)) = self.bounds {
+            if pos.x == min.x || pos.y == min.y || pos.x == max.x || pos.y == max.y {
+                self.bounds = self
+                    .cells
+                    .keys()
+                    .fold(None, |bounds, &pos| Some(extend(bounds, pos)));
+            }
+        }
+        Some(value)
+    }
+
+    fn grow(&mut self, pos: Vec2D<i32>) {
+        self.bounds = Some(extend(self.bounds, pos));
+    }
+
+    /// Returns the smallest and the largest corner of the box around all
+    /// set cells, None if there are none.
+    pub fn bounds(&self) -> Option<(Vec2D<i32>, Vec2D<i32>)> {
+        self.bounds
+    }
+
+    /// Iterates over the set cells in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2D<i32>, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// Copies the rectangle from `min` to `max`, both included, into a
+    /// [`Grid`]. Its top left cell is `min`.
+    pub fn crop(&self, min: Vec2D<i32>, max: Vec2D<i32>) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = (max.x - min.x + 1).max(0) as usize;
+        let height = (max.y - min.y + 1).max(0) as usize;
+        let cells = (0..height as i32)
+            .flat_map(|y| (0..width as i32).map(move |x| min + Vec2D::new(x, y)))
+            .map(|pos| self.get(pos).clone())
+            .collect();
+        Grid::from_vec(width, height, cells)
+    }
+
+    /// Copies the occupied area into a [`Grid`], see [`crop`](Self::crop).
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        match self.bounds {
+            Some((min, max)) => self.crop(min, max),
+            None => Grid::from_vec(0, 0, Vec::new()),
+        }
+    }
+
+    /// Draws the occupied area with one character per cell.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+        let mut out = String::new();
+        for y in min.y..=max.y {
+            out.extend((min.x..=max.x).map(|x| cell(self.get(Vec2D::new(x, y)))));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Returns the smallest box holding `bounds` and `pos`.
+fn extend(bounds: Option<(Vec2D<i32>, Vec2D<i32>)>, pos: Vec2D<i32>) -> (Vec2D<i32>, Vec2D<i32>) {
+    match bounds {
+        None => (pos, pos),
+        Some((min, max)) => (
+            Vec2D::new(min.x.min(pos.x), min.y.min(pos.y)),
+            Vec2D::new(max.x.max(pos.x), max.y.max(pos.y)),
+        ),
+    }
+}
+
+impl<T> Index<Vec2D<i32>> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2D<i32>) -> &Self::Output {
+        self.get(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cells() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(grid.set(Vec2D::new(-2, 3), '#'), None);
+        assert_eq!(grid.set(Vec2D::new(-2, 3), 'A'), Some('#'));
+        *grid.get_mut(Vec2D::new(1, -1)) = 'B';
+        assert_eq!(grid[Vec2D::new(-2, 3)], 'A');
+        assert_eq!(grid[Vec2D::new(100, 100)], '.');
+        assert!(!grid.contains(Vec2D::new(0, 0)));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some((Vec2D::new(-2, -1), Vec2D::new(1, 3))));
+
+        assert_eq!(grid.remove(Vec2D::new(1, -1)), Some('B'));
+        assert_eq!(grid.bounds(), Some((Vec2D::new(-2, 3), Vec2D::new(-2, 3))));
+        grid.remove(Vec2D::new(-2, 3));
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn crop_and_render() {
+        let mut grid = SparseGrid::new(false);
+        for pos in [Vec2D::new(-1, -1), Vec2D::new(1, 0)] {
+            grid.set(pos, true);
+        }
+        assert_eq!(grid.render(|&b| if b { '#' } else { '.' }), "#..\n..#\n");
+
+        let dense = grid.to_grid();
+        assert_eq!((dense.width(), dense.height()), (3, 2));
+        assert!(dense[Vec2D::new(2, 1)]);
+
+        let cropped = grid.crop(Vec2D::new(0, -1), Vec2D::new(2, 0));
+        assert_eq!(
+            cropped.rows().collect::<Vec<_>>(),
+            [[false, false, false], [false, true, false]]
+        );
+        assert_eq!(SparseGrid::<bool>::new(false).to_grid().width(), 0);
+    }
+}
@@ -0,0 +1,211 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    io::ErrorKind,
+    path::Path,
+    time::Duration,
+};
+
+use crate::{
+    fetch::{body, Client},
+    Error, Result,
+};
+
+/// The reply of the server to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reply {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint in which direction.
+    Wrong,
+    /// An answer was submitted too recently.
+    Wait(Duration),
+    /// The part is solved already, or not unlocked yet.
+    AlreadySolved,
+}
+
+impl Reply {
+    /// Parses the HTML page the server replies with.
+    pub fn parse(page: &str) -> Option<Self> {
+        // only the article holds the message, the rest is navigation
+        let text = page
+            .split_once("<article")
+            .and_then(|(_, a)| a.split_once("</article>"))
+            .map_or(page, |(a, _)| a);
+
+        let reply = if text.contains("That's the right answer") {
+            Reply::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Reply::TooHigh
+            } else if text.contains("too low") {
+                Reply::TooLow
+            } else {
+                Reply::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Reply::Wait(parse_wait(text).unwrap_or(Duration::from_secs(60)))
+        } else if text.contains("Did you already complete it?") {
+            Reply::AlreadySolved
+        } else {
+            return None;
+        };
+        Some(reply)
+    }
+
+    /// Returns true if the answer is known to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Reply::TooHigh | Reply::TooLow | Reply::Wrong)
+    }
+}
+
+impl Display for Reply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reply::Correct => write!(f, "correct"),
+            Reply::TooHigh => write!(f, "too high"),
+            Reply::TooLow => write!(f, "too low"),
+            Reply::Wrong => write!(f, "wrong"),
+            Reply::Wait(d) => write!(f, "wait {}s", d.as_secs()),
+            Reply::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Parses the time left in a message like `you have 1m 23s left to wait`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, time) = before.rsplit_once("have ")?;
+    let mut secs = 0;
+    for part in time.split_whitespace() {
+        let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+impl Client {
+    /// Submits `answer` for `part` of `day`.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Reply> {
+        let url = self.url(&format!("day/{day}/answer"));
+        let level = part.to_string();
+        let response = self
+            .request("POST", &url)
+            .send_form(&[("level", &level), ("answer", answer)]);
+        let page = body(&url, response)?;
+        Reply::parse(&page).ok_or_else(|| Error::Http {
+            url,
+            msg: "unexpected reply".to_string(),
+        })
+    }
+}
+
+/// Wrong answers submitted for a day, so they are never submitted twice.
+///
+/// Stored one per line as `partN <reply> <answer>`, like `part1 too-high 1234`.
+/// The answer is the rest of the line, so it may contain spaces. Answers are
+/// read from the lines of `dayN/output` and never span several lines.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Guesses {
+    wrong: Vec<(u8, Reply, String)>,
+}
+
+impl Guesses {
+    /// Loads the guesses stored at `path`. A missing file holds no guesses.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Parses one `partN <reply> <answer>` guess per line.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut guesses = Self::default();
+        for line in text.lines().filter(|l| !l.trim().is_empty()) {
+            // the answer comes last, as it may contain spaces
+            let mut fields = line.splitn(3, ' ');
+            let (Some(part), Some(reply), Some(answer)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(Error::at(text, line, "expected `partN <reply> <answer>`"));
+            };
+            let part = match part {
+                "part1" => 1,
+                "part2" => 2,
+                _ => return Err(Error::at(text, part, "expected `part1` or `part2`")),
+            };
+            let reply = match reply {
+                "too-high" => Reply::TooHigh,
+                "too-low" => Reply::TooLow,
+                "wrong" => Reply::Wrong,
+                _ => {
+                    return Err(Error::at(
+                        text,
+                        reply,
+                        "expected `too-high`, `too-low` or `wrong`",
+                    ))
+                }
+            };
+            guesses.wrong.push((part, reply, answer.to_string()));
+        }
+        Ok(guesses)
+    }
+
+    /// Writes all guesses to `path`, replacing its contents.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    /// Remembers `answer` to `part` if `reply` says it is wrong.
+    pub fn record(&mut self, part: u8, answer: &str, reply: Reply) {
+        if reply.is_wrong() {
+            self.wrong.push((part, reply, answer.to_string()));
+        }
+    }
+
+    /// Returns why `answer` to `part` is known to be wrong, if it is.
+    ///
+    /// Numeric answers are also checked against the bounds of earlier
+    /// too high and too low guesses.
+    pub fn check(&self, part: u8, answer: &str) -> Option<String> {
+        let number = answer.parse::<i64>().ok();
+        self.wrong
+            .iter()
+            .filter(|(p, ..)| *p == part)
+            .find_map(|(_, reply, guess)| {
+                if guess == answer {
+                    return Some(format!("{answer} was already guessed ({reply})"));
+                }
+                let (n, guess) = (number?, guess.parse::<i64>().ok()?);
+                match reply {
+                    Reply::TooHigh if n >= guess => {
+                        Some(format!("{answer} is not below {guess}, which is too high"))
+                    }
+                    Reply::TooLow if n <= guess => {
+                        Some(format!("{answer} is not above {guess}, which is too low"))
+                    }
+                    _ => None,
+                }
+            })
+    }
+}
+
+impl Display for Guesses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (part, reply, answer) in &self.wrong {
+            let reply = reply.to_string().replace(' ', "-");
+            writeln!(f, "part{part} {reply} {answer}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,326 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Rem, Sub, SubAssign};
+
+/// A vector in Num^2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vec2D<T>
+where
+    T: Add<Output = T>,
+{
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Add for Vec2D<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Vec2D<T>;
+
+    fn add(self, rhs: Vec2D<T>) -> Self::Output {
+        Vec2D {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T> AddAssign for Vec2D<T>
+where
+    T: Add<Output = T> + AddAssign,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T> Sub for Vec2D<T>
+where
+    T: Add<Output = T> + Sub<Output = T>,
+{
+    type Output = Vec2D<T>;
+
+    fn sub(self, rhs: Vec2D<T>) -> Self::Output {
+        Vec2D {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T> SubAssign for Vec2D<T>
+where
+    T: Add<Output = T> + SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T> Neg for Vec2D<T>
+where
+    T: Add<Output = T> + Neg<Output = T>,
+{
+    type Output = Vec2D<T>;
+
+    fn neg(self) -> Self::Output {
+        Vec2D {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T, I> Mul<I> for Vec2D<T>
+where
+    T: Add<Output = T> + Mul<I, Output = T>,
+    I: Copy,
+{
+    type Output = Vec2D<T>;
+
+    /// Scalar multiplication.
+    fn mul(self, rhs: I) -> Self::Output {
+        Vec2D {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T> Vec2D<T>
+where
+    T: Add<Output = T> + Mul<Output = T>,
+{
+    /// Component-wise multiplication. Not an operator, as `*` already
+    /// multiplies by any scalar.
+    pub fn mul_elem(self, rhs: Vec2D<T>) -> Self {
+        Vec2D {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+        }
+    }
+}
+
+impl<T> Rem for Vec2D<T>
+where
+    T: Add<Output = T> + Rem<Output = T> + Copy,
+{
+    type Output = Vec2D<T>;
+
+    /// Calculates the elementwise modulus.
+    fn rem(self, rhs: Self) -> Self::Output {
+        Vec2D {
+            x: ((self.x % rhs.x) + rhs.x) % rhs.x,
+            y: ((self.y % rhs.y) + rhs.y) % rhs.y,
+        }
+    }
+}
+
+impl<T> Ord for Vec2D<T>
+where
+    T: Ord + Add<Output = T>,
+{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.x.cmp(&other.x).then_with(|| self.y.cmp(&other.y))
+    }
+}
+
+impl<T> PartialOrd for Vec2D<T>
+where
+    T: Ord + Add<Output = T>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Vec2D<T>
+where
+    T: Add<Output = T>,
+{
+    pub const fn new(x: T, y: T) -> Self {
+        Vec2D { x, y }
+    }
+}
+
+/// Returns |a - b| without leaving the range of unsigned types.
+fn abs_diff<T>(a: T, b: T) -> T
+where
+    T: Sub<Output = T> + PartialOrd,
+{
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T> Vec2D<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + PartialOrd + Copy,
+{
+    /// Returns the taxicab distance |dx| + |dy| to `other`.
+    pub fn manhattan(self, other: Vec2D<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Returns the chessboard distance max(|dx|, |dy|) to `other`.
+    pub fn chebyshev(self, other: Vec2D<T>) -> T {
+        let dx = abs_diff(self.x, other.x);
+        let dy = abs_diff(self.y, other.y);
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+}
+
+impl<T> Vec2D<T>
+where
+    T: Add<Output = T> + Neg<Output = T>,
+{
+    /// Rotates by 90 degrees clockwise, with y pointing down like in [`SOUTH`].
+    pub fn rotate_cw(self) -> Self {
+        Vec2D {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Rotates by 90 degrees counterclockwise, with y pointing down like in [`SOUTH`].
+    pub fn rotate_ccw(self) -> Self {
+        Vec2D {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+}
+
+impl<T> Vec2D<T>
+where
+    T: Add<Output = T> + Neg<Output = T> + PartialOrd + Default + From<i8>,
+{
+    /// Returns the sign (-1, 0 or 1) of every component.
+    pub fn signum(self) -> Self {
+        let sign = |v: T| match v.partial_cmp(&T::default()) {
+            Some(std::cmp::Ordering::Greater) => T::from(1),
+            Some(std::cmp::Ordering::Less) => T::from(-1),
+            _ => T::default(),
+        };
+        Vec2D {
+            x: sign(self.x),
+            y: sign(self.y),
+        }
+    }
+}
+
+impl<T> Vec2D<T>
+where
+    T: Add<Output = T> + PartialOrd + Default,
+{
+    /// Adds `rhs` if the result lies in `0 <= x < w` and `0 <= y < h`.
+    pub fn bounded_add(self, rhs: Vec2D<T>, w: T, h: T) -> Option<Vec2D<T>> {
+        let nx = self.x + rhs.x;
+        let ny = self.y + rhs.y;
+        let zero = T::default();
+        if zero <= nx && nx < w && zero <= ny && ny < h {
+            Some(Vec2D { x: nx, y: ny })
+        } else {
+            None
+        }
+    }
+}
+
+// i32 types
+pub const NORTH: Vec2D<i32> = Vec2D::new(0, -1);
+pub const WEST: Vec2D<i32> = Vec2D::new(-1, 0);
+pub const SOUTH: Vec2D<i32> = Vec2D::new(0, 1);
+pub const EAST: Vec2D<i32> = Vec2D::new(1, 0);
+
+pub const NORTH_WEST: Vec2D<i32> = Vec2D::new(-1, -1);
+pub const SOUTH_WEST: Vec2D<i32> = Vec2D::new(-1, 1);
+pub const SOUTH_EAST: Vec2D<i32> = Vec2D::new(1, 1);
+pub const NORTH_EAST: Vec2D<i32> = Vec2D::new(1, -1);
+
+pub const CARDINAL: [Vec2D<i32>; 4] = [NORTH, WEST, SOUTH, EAST];
+pub const DIAGONAL: [Vec2D<i32>; 4] = [NORTH_WEST, SOUTH_WEST, SOUTH_EAST, NORTH_EAST];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Vec2D::new(3, -2);
+        let b = Vec2D::new(-1, 5);
+
+        assert_eq!(a + b, Vec2D::new(2, 3));
+        assert_eq!(a - b, Vec2D::new(4, -7));
+        assert_eq!(-a, Vec2D::new(-3, 2));
+        assert_eq!(a * 3, Vec2D::new(9, -6));
+        // a scalar of another type than the components
+        let second = std::time::Duration::from_secs(1);
+        assert_eq!(
+            Vec2D::new(second, second * 2) * 3u32,
+            Vec2D::new(second * 3, second * 6)
+        );
+        assert_eq!(a.mul_elem(b), Vec2D::new(-3, -10));
+        assert_eq!(Vec2D::new(-7, 7) % Vec2D::new(5, 5), Vec2D::new(3, 2));
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a + b);
+        c -= b;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec2D::new(1, 5);
+        let b = Vec2D::new(4, 1);
+
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.manhattan(a), 0);
+        // unsigned coordinates don't underflow
+        assert_eq!(Vec2D::<usize>::new(1, 5).manhattan(Vec2D::new(4, 1)), 7);
+        assert_eq!(Vec2D::<usize>::new(1, 5).chebyshev(Vec2D::new(4, 1)), 4);
+    }
+
+    #[test]
+    fn rotation() {
+        assert_eq!(NORTH.rotate_cw(), EAST);
+        assert_eq!(EAST.rotate_cw(), SOUTH);
+        assert_eq!(SOUTH.rotate_cw(), WEST);
+        assert_eq!(WEST.rotate_cw(), NORTH);
+        assert_eq!(NORTH_EAST.rotate_cw(), SOUTH_EAST);
+        for d in CARDINAL.into_iter().chain(DIAGONAL) {
+            assert_eq!(d.rotate_cw().rotate_ccw(), d);
+            assert_eq!(d.rotate_cw().rotate_cw(), -d);
+        }
+    }
+
+    #[test]
+    fn signum() {
+        assert_eq!(Vec2D::new(-4, 9).signum(), Vec2D::new(-1, 1));
+        assert_eq!(Vec2D::new(0, -3i64).signum(), Vec2D::new(0, -1));
+    }
+
+    #[test]
+    fn bounded_add() {
+        let p = Vec2D::new(0, 2);
+
+        assert_eq!(p.bounded_add(EAST, 3, 3), Some(Vec2D::new(1, 2)));
+        assert_eq!(p.bounded_add(WEST, 3, 3), None);
+        assert_eq!(p.bounded_add(SOUTH, 3, 3), None);
+        assert_eq!(p.bounded_add(NORTH * 2, 3, 3), Some(Vec2D::new(0, 0)));
+        assert_eq!(p.bounded_add(NORTH * 3, 3, 3), None);
+        assert_eq!(
+            Vec2D::<usize>::new(1, 1).bounded_add(Vec2D::new(1, 0), 2, 2),
+            None
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    io::ErrorKind,
+    path::Path,
+};
+
+use crate::{record::json_string, Error, Result};
+
+/// Confirmed answers of every day and part, stored in a small TOML file:
+///
+/// ```toml
+/// [day1]
+/// part1 = "1234"
+/// part2 = "5678"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KnownAnswers {
+    days: BTreeMap<u8, [Option<String>; 2]>,
+}
+
+/// The result of comparing an answer to the known one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// The answer differs from the contained known answer.
+    Wrong(String),
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "✓"),
+            Verdict::Wrong(expected) => write!(f, "✗ expected {expected}"),
+            Verdict::Unknown => write!(f, "?"),
+        }
+    }
+}
+
+/// Returns the index of `part` in the per-day array.
+fn part_index(part: u8) -> Result<usize> {
+    match part {
+        1 | 2 => Ok(part as usize - 1),
+        _ => Err(Error::Part(part)),
+    }
+}
+
+impl KnownAnswers {
+    /// Loads the answers stored at `path`. A missing file holds no answers.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Parses `[dayN]` sections holding `part1` and `part2` keys.
+    pub fn parse(text: &str) -> Result<Self> {
+        let sections =
+            parse_sections(text, "dayN", |s| s.strip_prefix("day")?.parse().ok(), false)?;
+        let days = sections.into_iter().map(|(k, s)| (k, s.parts)).collect();
+        Ok(KnownAnswers { days })
+    }
+
+    /// Writes all answers to `path`, replacing its contents.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    /// Returns the known answer of `part` of `day`, None if there is none or
+    /// `part` is neither 1 nor 2.
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.days.get(&day)?[part_index(part).ok()?].as_deref()
+    }
+
+    /// Stores `answer` as the known answer of `part` of `day`.
+    pub fn set(&mut self, day: u8, part: u8, answer: impl Into<String>) -> Result<()> {
+        let i = part_index(part)?;
+        self.days.entry(day).or_default()[i] = Some(answer.into());
+        Ok(())
+    }
+
+    /// Compares `answer` to the known answer of `part` of `day`.
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Result<Verdict> {
+        part_index(part)?;
+        Ok(match self.get(day, part) {
+            Some(known) if known == answer => Verdict::Correct,
+            Some(known) => Verdict::Wrong(known.to_string()),
+            None => Verdict::Unknown,
+        })
+    }
+}
+
+/// The answers of a section, and the puzzle parameters they hold for.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Section {
+    pub parts: [Option<String>; 2],
+    /// `param.KEY = value` entries.
+    pub params: BTreeMap<String, String>,
+}
+
+/// Parses sections of `part1` and `part2` answers, keyed by the section name.
+///
+/// `key` converts a section name, `what` describes a valid one for errors.
+/// Values are strings or bare integers, `#` starts a comment outside of
+/// strings. Sections
+/// may also set `param.KEY` entries if `params` is true.
+pub(crate) fn parse_sections<K: Ord>(
+    text: &str,
+    what: &str,
+    key: impl Fn(&str) -> Option<K>,
+    params: bool,
+) -> Result<BTreeMap<K, Section>> {
+    let mut sections: BTreeMap<K, Section> = BTreeMap::new();
+    let mut current = None;
+    for line in text.lines() {
+        let trimmed = strip_comment(line).trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some(section) = trimmed.strip_prefix('[') {
+            let k = section
+                .strip_suffix(']')
+                .and_then(&key)
+                .ok_or_else(|| Error::at(text, trimmed, format!("expected section `[{what}]`")))?;
+            current = Some(sections.entry(k).or_default());
+            continue;
+        }
+
+        let section = current
+            .as_mut()
+            .ok_or_else(|| Error::at(text, trimmed, format!("expected `[{what}]` first")))?;
+        let (k, value) = trimmed
+            .split_once('=')
+            .ok_or_else(|| Error::at(text, trimmed, "expected `partN = \"answer\"`"))?;
+        let value = value.trim();
+        let value = match value.strip_prefix('"') {
+            Some(_) => unquote(text, value)?,
+            None if value.parse::<i64>().is_ok() => value.to_string(),
+            None => return Err(Error::at(text, value, "expected string or integer")),
+        };
+        match k.trim() {
+            "part1" => section.parts[0] = Some(value),
+            "part2" => section.parts[1] = Some(value),
+            k => match k.strip_prefix("param.") {
+                Some(name) if params => {
+                    section.params.insert(name.to_string(), value);
+                }
+                _ if params => {
+                    return Err(Error::at(
+                        text,
+                        k,
+                        "expected `part1`, `part2` or `param.KEY`",
+                    ))
+                }
+                _ => return Err(Error::at(text, k, "expected key `part1` or `part2`")),
+            },
+        }
+    }
+    Ok(sections)
+}
+
+/// Returns `line` up to a `#` that is not inside a string.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Reads the quoted string `value`, a slice of `text`, resolving escapes.
+fn unquote(text: &str, value: &str) -> Result<String> {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.char_indices().skip(1);
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' if i + 1 == value.len() => return Ok(out),
+            '"' => {
+                return Err(Error::at(
+                    text,
+                    &value[i + 1..],
+                    "unexpected text after string",
+                ))
+            }
+            '\\' => {
+                let escape = &value[i..];
+                let unknown = || Error::at(text, escape, "unknown escape sequence");
+                let c = match chars.next().ok_or_else(unknown)?.1 {
+                    '"' => '"',
+                    '\\' => '\\',
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    'u' => {
+                        let hex: String = (0..4)
+                            .filter_map(|_| chars.next())
+                            .map(|(_, c)| c)
+                            .collect();
+                        Some(&hex)
+                            .filter(|h| h.len() == 4 && h.chars().all(|c| c.is_ascii_hexdigit()))
+                            .and_then(|h| u32::from_str_radix(h, 16).ok())
+                            .and_then(char::from_u32)
+                            .ok_or_else(unknown)?
+                    }
+                    _ => return Err(unknown()),
+                };
+                out.push(c);
+            }
+            c => out.push(c),
+        }
+    }
+    Err(Error::at(text, value, "unterminated string"))
+}
+
+impl Display for KnownAnswers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (day, parts)) in self.days.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "[day{day}]")?;
+            for (part, answer) in parts.iter().enumerate() {
+                if let Some(answer) = answer {
+                    // TOML basic strings escape like JSON
+                    writeln!(f, "part{} = {}", part + 1, json_string(answer))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let text = "# confirmed\n[day1] # easy\npart1 = \"abc\" # first try\npart2 = 42#x\n\n[day3]\npart2 = \"1,#2\"\n";
+        let known = KnownAnswers::parse(text).unwrap();
+        assert_eq!(known.get(1, 1), Some("abc"));
+        assert_eq!(known.get(1, 2), Some("42"));
+        assert_eq!(known.get(3, 1), None);
+        assert_eq!(known.get(3, 2), Some("1,#2"));
+        assert_eq!(known.get(2, 1), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        for (text, msg) in [
+            ("part1 = 1", "expected `[dayN]` first"),
+            ("[dayx]", "expected section `[dayN]`"),
+            ("[day1]\npart3 = 1", "expected key `part1` or `part2`"),
+            ("[day1]\npart1 = abc", "expected string or integer"),
+            ("[day1]\npart1 = \"abc", "unterminated string"),
+            ("[day1]\npart1 = \"a\"b\"", "unexpected text after string"),
+            ("[day1]\npart1 = \"\\x\"", "unknown escape sequence"),
+            ("[day1]\npart1 = \"\\u+123\"", "unknown escape sequence"),
+        ] {
+            let e = KnownAnswers::parse(text).unwrap_err();
+            assert!(e.to_string().starts_with(msg), "{text:?}: {e}");
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut known = KnownAnswers::default();
+        known.set(2, 1, "say \"hi\" #1").unwrap();
+        known.set(2, 2, "back\\slash\nnew\u{1}line").unwrap();
+        known.set(10, 2, "7").unwrap();
+        let text = known.to_string();
+        assert_eq!(
+            text,
+            "[day2]\npart1 = \"say \\\"hi\\\" #1\"\npart2 = \"back\\\\slash\\nnew\\u0001line\"\n\n[day10]\npart2 = \"7\"\n"
+        );
+        assert_eq!(KnownAnswers::parse(&text).unwrap(), known);
+    }
+
+    #[test]
+    fn check() {
+        let mut known = KnownAnswers::default();
+        known.set(5, 1, "143").unwrap();
+        assert_eq!(known.check(5, 1, "143").unwrap(), Verdict::Correct);
+        assert_eq!(
+            known.check(5, 1, "142").unwrap(),
+            Verdict::Wrong("143".to_string())
+        );
+        assert_eq!(known.check(5, 2, "123").unwrap(), Verdict::Unknown);
+        assert_eq!(known.check(6, 1, "143").unwrap(), Verdict::Unknown);
+
+        assert_eq!(Verdict::Correct.to_string(), "✓");
+        assert_eq!(
+            Verdict::Wrong("143".to_string()).to_string(),
+            "✗ expected 143"
+        );
+        assert_eq!(Verdict::Unknown.to_string(), "?");
+    }
+
+    #[test]
+    fn invalid_parts() {
+        let mut known = KnownAnswers::default();
+        assert_eq!(
+            known.set(5, 3, "1").unwrap_err().to_string(),
+            "invalid part 3, expected 1 or 2"
+        );
+        assert!(known.check(5, 0, "1").is_err());
+        assert_eq!(known.get(5, 3), None);
+        assert_eq!(known, KnownAnswers::default());
+    }
+}
@@ -0,0 +1,252 @@
+//! Pictures of grids and point sets, for the terminal and as images.
+//!
+//! Rendering works everywhere, writing is opt-in: [`show`], [`save`] and
+//! [`Frames`] do nothing until [`enable`] was called, which the runner does
+//! for `aoc run --viz`. So days can leave their visualisations in place.
+
+use std::{
+    fmt::Write as _,
+    fs,
+    ops::Add,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use crate::{Grid, Vec2D};
+
+/// A 24 bit colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(110, 110, 110);
+    pub const RED: Rgb = Rgb(230, 60, 50);
+    pub const GREEN: Rgb = Rgb(60, 200, 80);
+    pub const BLUE: Rgb = Rgb(70, 120, 230);
+    pub const YELLOW: Rgb = Rgb(240, 210, 60);
+    pub const BROWN: Rgb = Rgb(160, 100, 40);
+}
+
+static OUTPUT: OnceLock<PathBuf> = OnceLock::new();
+
+/// Turns visualisations on, writing files below `dir`.
+pub fn enable(dir: impl Into<PathBuf>) {
+    let _ = OUTPUT.set(dir.into());
+}
+
+/// Returns true if visualisations were turned on.
+pub fn enabled() -> bool {
+    OUTPUT.get().is_some()
+}
+
+/// Renders `grid` with one coloured character per cell, as given by `cell`.
+pub fn to_ansi<T>(grid: &Grid<T>, mut cell: impl FnMut(&T) -> (char, Rgb)) -> String {
+    let mut out = String::new();
+    for row in grid.rows() {
+        let mut last = None;
+        for value in row {
+            let (c, color) = cell(value);
+            // only switch colours when they change, most cells repeat them
+            if last != Some(color) {
+                let Rgb(r, g, b) = color;
+                let _ = write!(out, "\x1b[38;2;{r};{g};{b}m");
+                last = Some(color);
+            }
+            out.push(c);
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+/// Renders `grid` as a plain PBM image, black where `grid` is true.
+pub fn to_pbm(grid: &Grid<bool>) -> Vec<u8> {
+    let mut out = format!("P1\n{} {}\n", grid.width(), grid.height());
+    for row in grid.rows() {
+        let bits: Vec<_> = row.iter().map(|&b| if b { "1" } else { "0" }).collect();
+        out += &bits.join(" ");
+        out.push('\n');
+    }
+    out.into_bytes()
+}
+
+/// Renders `grid` as a binary PPM image with `scale` x `scale` pixels per
+/// cell, coloured by `color`.
+pub fn to_ppm<T>(grid: &Grid<T>, scale: usize, mut color: impl FnMut(&T) -> Rgb) -> Vec<u8> {
+    let (w, h) = (grid.width() * scale, grid.height() * scale);
+    let mut out = format!("P6\n{w} {h}\n255\n").into_bytes();
+    out.reserve(w * h * 3);
+    for row in grid.rows() {
+        let pixels: Vec<u8> = row
+            .iter()
+            .flat_map(|v| {
+                let Rgb(r, g, b) = color(v);
+                [r, g, b].repeat(scale)
+            })
+            .collect();
+        for _ in 0..scale {
+            out.extend_from_slice(&pixels);
+        }
+    }
+    out
+}
+
+/// Marks `points` in a grid just large enough to hold them.
+///
+/// The top left corner of the grid is the smallest x and y of all points.
+pub fn points<I>(points: impl IntoIterator<Item = Vec2D<I>>) -> Grid<bool>
+where
+    I: Add<Output = I> + TryInto<i64>,
+{
+    let points: Vec<_> = points
+        .into_iter()
+        .filter_map(|p| Some((p.x.try_into().ok()?, p.y.try_into().ok()?)))
+        .collect();
+    let min_x = points.iter().map(|p| p.0).min().unwrap_or(0);
+    let min_y = points.iter().map(|p| p.1).min().unwrap_or(0);
+    let width = points.iter().map(|p| p.0 - min_x + 1).max().unwrap_or(0);
+    let height = points.iter().map(|p| p.1 - min_y + 1).max().unwrap_or(0);
+
+    let mut grid = Grid::new(width as usize, height as usize, false);
+    for (x, y) in points {
+        grid[Vec2D::new(x - min_x, y - min_y)] = true;
+    }
+    grid
+}
+
+/// Prints the rendering of [`to_ansi`] to stderr if visualisations are on.
+pub fn show<T>(grid: &Grid<T>, cell: impl FnMut(&T) -> (char, Rgb)) {
+    if enabled() {
+        eprint!("{}", to_ansi(grid, cell));
+    }
+}
+
+/// Writes `data` to the file `name` below the output directory if
+/// visualisations are on. Failures are reported on stderr.
+pub fn save(name: &str, data: &[u8]) {
+    if let Some(dir) = OUTPUT.get() {
+        if let Err(e) = write(&dir.join(name), data) {
+            eprintln!("visualisation: {e}");
+        }
+    }
+}
+
+fn write(path: &Path, data: &[u8]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("{}: {e}", parent.display()))?;
+    }
+    fs::write(path, data).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// An animation, written as numbered PPM images `00000.ppm`, `00001.ppm`, …
+/// into a directory of its own.
+///
+/// Tools like ffmpeg turn them into a video:
+/// `ffmpeg -i viz/day15/%05d.ppm day15.mp4`.
+#[derive(Debug)]
+pub struct Frames {
+    /// None if visualisations are off or writing failed.
+    dir: Option<PathBuf>,
+    scale: usize,
+    count: usize,
+}
+
+impl Frames {
+    /// Starts an animation in the directory `name` below the output
+    /// directory, with `scale` x `scale` pixels per cell.
+    pub fn new(name: &str, scale: usize) -> Self {
+        Frames {
+            dir: OUTPUT.get().map(|dir| dir.join(name)),
+            scale,
+            count: 0,
+        }
+    }
+
+    /// Returns true if frames are being written.
+    pub fn recording(&self) -> bool {
+        self.dir.is_some()
+    }
+
+    /// Appends a frame showing `grid`, coloured by `color`.
+    pub fn push<T>(&mut self, grid: &Grid<T>, color: impl FnMut(&T) -> Rgb) {
+        let Some(dir) = &self.dir else {
+            return;
+        };
+        let path = dir.join(format!("{:05}.ppm", self.count));
+        match write(&path, &to_ppm(grid, self.scale, color)) {
+            Ok(()) => self.count += 1,
+            Err(e) => {
+                eprintln!("visualisation: {e}, stopping the animation");
+                self.dir = None;
+            }
+        }
+    }
+
+    /// Returns the number of frames written.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn images() {
+        let grid = Grid::from_vec(2, 2, vec![true, false, false, true]);
+        assert_eq!(to_pbm(&grid), b"P1\n2 2\n1 0\n0 1\n");
+
+        let ppm = to_ppm(&grid, 2, |&b| if b { Rgb::WHITE } else { Rgb::BLACK });
+        let (header, pixels) = ppm.split_at(b"P6\n4 4\n255\n".len());
+        assert_eq!(header, b"P6\n4 4\n255\n");
+        assert_eq!(pixels.len(), 4 * 4 * 3);
+        assert_eq!(
+            pixels[..12],
+            [255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(pixels[12..24], pixels[..12]);
+        assert_eq!(
+            pixels[36..],
+            [0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255]
+        );
+    }
+
+    #[test]
+    fn ansi() {
+        let grid = Grid::from_vec(3, 1, vec!['#', '#', '.']);
+        let text = to_ansi(&grid, |&c| match c {
+            '#' => (c, Rgb::RED),
+            _ => (c, Rgb::GRAY),
+        });
+        assert_eq!(
+            text,
+            "\x1b[38;2;230;60;50m##\x1b[38;2;110;110;110m.\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn points() {
+        let grid = super::points([Vec2D::new(-1, 3), Vec2D::new(1, 4), Vec2D::new(-1, 4)]);
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [[true, false, false], [true, false, true]]
+        );
+        assert_eq!(super::points::<i32>([]).width(), 0);
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let mut frames = Frames::new("test", 1);
+        frames.push(&Grid::new(1, 1, ()), |_| Rgb::BLACK);
+        assert!(!frames.recording());
+        assert!(frames.is_empty());
+    }
+}
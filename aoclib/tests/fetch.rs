@@ -0,0 +1,115 @@
+mod common;
+
+use std::{env, fs, path::PathBuf, process};
+
+use aoclib::{
+    fetch::{read_session, Client, USER_AGENT},
+    Error,
+};
+use common::Server;
+
+const INPUT: &str = "3   4\n4   3\n";
+
+/// Serves the input of day 1, a login page for day 2 and 404 for the rest.
+fn server() -> Server {
+    Server::start(|request| match request.path.as_str() {
+        "/2024/day/1/input" if request.header("Cookie") == Some("session=secret") => {
+            (200, INPUT.to_string())
+        }
+        "/2024/day/1/input" | "/2024/day/2/input" => (
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string(),
+        ),
+        _ => (
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!".to_string(),
+        ),
+    })
+}
+
+/// Returns a fresh directory for the test `name`.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoclib-fetch-{}-{name}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn downloads_input_with_session_and_user_agent() {
+    let server = server();
+    let client = Client::new(&server.url, 2024, "secret");
+
+    assert_eq!(client.input(1).unwrap(), INPUT);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].header("User-Agent"), Some(USER_AGENT));
+    assert!(USER_AGENT.contains("github.com/michihupf/aoc24"));
+}
+
+#[test]
+fn detects_login_page() {
+    let server = server();
+
+    let err = Client::new(&server.url, 2024, "expired")
+        .input(1)
+        .unwrap_err();
+    assert!(matches!(err, Error::Session(_)), "{err}");
+    let err = Client::new(&server.url, 2024, "secret")
+        .input(2)
+        .unwrap_err();
+    assert!(matches!(err, Error::Session(_)), "{err}");
+}
+
+#[test]
+fn detects_locked_puzzle() {
+    let server = server();
+
+    let err = Client::new(&server.url, 2024, "secret")
+        .input(25)
+        .unwrap_err();
+    assert!(matches!(err, Error::Http { .. }), "{err}");
+    assert!(err.to_string().contains("not found"), "{err}");
+}
+
+#[test]
+fn caches_input_and_refuses_to_overwrite() {
+    let server = server();
+    let client = Client::new(&server.url, 2024, "secret");
+    let path = temp_dir("cache").join("input");
+
+    assert_eq!(client.save_input(1, &path).unwrap(), INPUT);
+    assert_eq!(fs::read_to_string(&path).unwrap(), INPUT);
+
+    fs::write(&path, "edited").unwrap();
+    let err = client.save_input(1, &path).unwrap_err();
+    assert!(matches!(err, Error::Exists(_)), "{err}");
+    // the cached input is used without another request
+    assert_eq!(client.cached_input(1, &path).unwrap(), "edited");
+    assert_eq!(server.requests().len(), 1);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "edited");
+}
+
+#[test]
+fn failed_download_leaves_no_input() {
+    let server = server();
+    let path = temp_dir("failed").join("input");
+
+    let client = Client::new(&server.url, 2024, "secret");
+    assert!(client.cached_input(25, &path).is_err());
+    assert!(!path.exists());
+}
+
+#[test]
+fn reads_session_file() {
+    let dir = temp_dir("session");
+    let path = dir.join(".aocrc");
+
+    assert!(matches!(read_session(&path), Err(Error::Session(_))));
+    fs::write(&path, "  \n").unwrap();
+    assert!(matches!(read_session(&path), Err(Error::Session(_))));
+    fs::write(&path, "secret\n").unwrap();
+    assert_eq!(read_session(&path).unwrap(), "secret");
+}
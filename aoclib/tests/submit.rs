@@ -0,0 +1,127 @@
+mod common;
+
+use std::time::Duration;
+
+use aoclib::{
+    fetch::Client,
+    submit::{Guesses, Reply},
+    Error,
+};
+use common::Server;
+
+/// Wraps `message` like the answer page of adventofcode.com.
+fn page(message: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html><body><header>Advent of Code</header>\
+         <main><article><p>{message}</p></article></main></body></html>"
+    )
+}
+
+/// Day 1 has the answers 42 and 1,2,3. Day 2 is rate limited, day 3 solved.
+fn server() -> Server {
+    Server::start(|request| {
+        let form = |key: &str| {
+            request
+                .body
+                .split('&')
+                .find_map(|kv| kv.strip_prefix(key)?.strip_prefix('='))
+                .unwrap_or_default()
+                .to_string()
+        };
+        let message = match (request.path.as_str(), form("level").as_str()) {
+            ("/2024/day/1/answer", "1") => match form("answer").parse::<i64>() {
+                Ok(42) => "That's the right answer!  You are one gold star closer.",
+                Ok(n) if n > 42 => "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.",
+                Ok(_) => "That's not the right answer; your answer is too low.",
+                Err(_) => "That's not the right answer.  If you're stuck, try the subreddit.",
+            },
+            ("/2024/day/1/answer", "2") if form("answer") == "1%2C2%2C3" => {
+                "That's the right answer!"
+            }
+            ("/2024/day/2/answer", _) => {
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. [Return to Day 2]"
+            }
+            ("/2024/day/3/answer", _) => {
+                "You don't seem to be solving the right level.  Did you already complete it? [Return to Day 3]"
+            }
+            _ => return (200, "Puzzle inputs differ by user.  Please log in.".to_string()),
+        };
+        (200, page(message))
+    })
+}
+
+#[test]
+fn posts_level_and_answer() {
+    let server = server();
+    let client = Client::new(&server.url, 2024, "secret");
+
+    assert_eq!(client.submit(1, 1, "42").unwrap(), Reply::Correct);
+    assert_eq!(client.submit(1, 2, "1,2,3").unwrap(), Reply::Correct);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2024/day/1/answer");
+    assert_eq!(requests[0].body, "level=1&answer=42");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+}
+
+#[test]
+fn parses_replies() {
+    let server = server();
+    let client = Client::new(&server.url, 2024, "secret");
+
+    assert_eq!(client.submit(1, 1, "100").unwrap(), Reply::TooHigh);
+    assert_eq!(client.submit(1, 1, "7").unwrap(), Reply::TooLow);
+    assert_eq!(client.submit(1, 1, "abc").unwrap(), Reply::Wrong);
+    assert_eq!(
+        client.submit(2, 1, "1").unwrap(),
+        Reply::Wait(Duration::from_secs(83))
+    );
+    assert_eq!(client.submit(3, 2, "1").unwrap(), Reply::AlreadySolved);
+
+    let err = client.submit(4, 1, "1").unwrap_err();
+    assert!(matches!(err, Error::Session(_)), "{err}");
+}
+
+#[test]
+fn parses_wait_times() {
+    let wait = |time: &str| {
+        Reply::parse(&page(&format!(
+        "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {time} left to wait."
+    )))
+    };
+
+    assert_eq!(wait("45s"), Some(Reply::Wait(Duration::from_secs(45))));
+    assert_eq!(wait("4m 2s"), Some(Reply::Wait(Duration::from_secs(242))));
+    assert_eq!(Reply::parse(&page("Something else.")), None);
+}
+
+#[test]
+fn remembers_wrong_guesses() {
+    let mut guesses = Guesses::default();
+    guesses.record(1, "100", Reply::TooHigh);
+    guesses.record(1, "7", Reply::TooLow);
+    guesses.record(2, "abc", Reply::Wrong);
+    guesses.record(2, "42", Reply::Correct);
+    guesses.record(2, "a b  c ", Reply::Wrong);
+
+    assert!(guesses.check(1, "100").is_some());
+    assert!(guesses.check(1, "150").is_some());
+    assert!(guesses.check(1, "3").is_some());
+    assert!(guesses.check(1, "42").is_none());
+    assert!(guesses.check(2, "abc").is_some());
+    assert!(guesses.check(2, "42").is_none());
+    assert!(guesses.check(2, "a b  c ").is_some());
+    assert!(guesses.check(2, "a b c").is_none());
+    // bounds only apply to their part
+    assert!(guesses.check(2, "150").is_none());
+
+    let text = guesses.to_string();
+    assert_eq!(
+        text,
+        "part1 too-high 100\npart1 too-low 7\npart2 wrong abc\npart2 wrong a b  c \n"
+    );
+    assert_eq!(Guesses::parse(&text).unwrap(), guesses);
+    assert!(Guesses::parse("part3 wrong 1").is_err());
+    assert!(Guesses::parse("part1 wrong").is_err());
+}
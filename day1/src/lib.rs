@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+use aoclib::{parse, Error, Result, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = (Vec<u32>, Vec<u32>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1((left, right): &Self::Input) -> impl Display {
+        p1(left, right)
+    }
+
+    fn part2((left, right): &Self::Input) -> impl Display {
+        p2(left, right)
+    }
+}
+
+/// Parses both lists and sorts them.
+fn parse(input: &str) -> Result<(Vec<u32>, Vec<u32>)> {
+    let pairs = parse::map_lines(input, |line| match parse::ints::<u32>(line)?[..] {
+        [l, r] => Ok((l, r)),
+        _ => Err(Error::at(line, line, "expected two numbers")),
+    })?;
+
+    let (mut left, mut right): (Vec<u32>, Vec<u32>) = pairs.into_iter().unzip();
+    // Sorting does not need to be stable - relative order does not matter.
+    left.sort_unstable();
+    right.sort_unstable();
+
+    Ok((left, right))
+}
+
+#[inline]
+fn p1(left: &[u32], right: &[u32]) -> u32 {
+    left.iter()
+        .zip(right.iter())
+        .map(|(&l, &r)| l.abs_diff(r))
+        .sum()
+}
+
+#[inline]
+fn p2(left: &[u32], right: &[u32]) -> u32 {
+    // as the list are sorted we can traverse the list linearly and not miss any number.
+    // we start all the way at the start.
+    let mut r_idx = 0;
+    let mut sum = 0;
+    let mut prev_l = 0;
+    let mut count = 0;
+
+    for &l in left {
+        // update counter if l != prev_l
+        if l != prev_l {
+            count = 0;
+            while r_idx < right.len() && l >= right[r_idx] {
+                if l == right[r_idx] {
+                    count += 1;
+                }
+                r_idx += 1;
+            }
+        }
+        sum += l * count;
+        prev_l = l;
+    }
+
+    sum
+}
+
+aoclib::examples!(Day1);
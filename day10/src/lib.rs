@@ -0,0 +1,69 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use aoclib::{Grid, Result, Solution, Vec2D};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Grid<i8>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::try_parse_with(input, |c| c.to_digit(10).map(|d| d as i8))
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
+}
+
+#[inline]
+fn p1(map: &Grid<i8>) -> i32 {
+    calculate_scores(map, false).iter().sum()
+}
+
+#[inline]
+fn p2(map: &Grid<i8>) -> i32 {
+    calculate_scores(map, true).iter().sum()
+}
+
+/// Calculates the scores for all heads in reading order. If `rating` is true then calculates ratings instead.
+fn calculate_scores(map: &Grid<i8>, rating: bool) -> Vec<i32> {
+    let heads: Vec<Vec2D<i32>> = map
+        .iter()
+        .filter_map(|(pos, &level)| (level == 0).then_some(pos))
+        .collect();
+
+    let mut scores = vec![0; heads.len()];
+
+    // from each head search trails using bfs for all trails - they are all equal length
+    heads.iter().enumerate().for_each(|(i, &pos)| {
+        let mut explored = Grid::new(map.width(), map.height(), false);
+        let mut q = VecDeque::new();
+        explored[pos] = true;
+        q.push_back(pos);
+        while let Some(v) = q.pop_front() {
+            if map[v] == 9 {
+                scores[i] += 1;
+            } else {
+                // search every direction
+                for n in map.neighbours(v) {
+                    if !explored[n] && map[n] == map[v] + 1 {
+                        if !rating {
+                            explored[n] = true;
+                        }
+                        q.push_back(n);
+                    }
+                }
+            }
+        }
+    });
+
+    scores
+}
+
+aoclib::examples!(Day10);
@@ -0,0 +1,76 @@
+use std::{fmt::Display, iter::successors};
+
+use aoclib::{memo::Memo, parse_field, Result, Solution};
+
+type Stone = u64;
+
+fn num_digits(num: u64) -> u32 {
+    successors(Some(1), |&x| (x <= num).then(|| x * 10)).count() as u32 - 1
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Stone>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .split_whitespace()
+            .map(|x| parse_field(input, x))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
+}
+
+fn blink(stone: Stone, blinks: i32, memo: &mut Memo<(Stone, i32), u64>) -> u64 {
+    if blinks == 0 {
+        return 1;
+    }
+
+    // same number of blinks and same stone value means everything will be the same from now on
+    memo.get_or_compute(&(stone, blinks), |memo| {
+        if stone == 0 {
+            blink(1, blinks - 1, memo)
+        } else {
+            let digits = num_digits(stone);
+            if digits.is_multiple_of(2) {
+                let middle = 10u64.pow(digits / 2);
+                let (l, r) = (stone / middle, stone % middle);
+                blink(l, blinks - 1, memo) + blink(r, blinks - 1, memo)
+            } else {
+                blink(stone * 2024, blinks - 1, memo)
+            }
+        }
+    })
+}
+
+/// Returns the number of stones after `blinks` blinks.
+fn count(stones: &[Stone], blinks: i32) -> u64 {
+    let mut memo = Memo::new();
+    let count = stones
+        .iter()
+        .map(|&stone| blink(stone, blinks, &mut memo))
+        .sum();
+    aoclib::debug!("blink cache: {}", memo.stats());
+    count
+}
+
+#[inline]
+fn p1(stones: &[Stone]) -> u64 {
+    count(stones, 25)
+}
+
+#[inline]
+fn p2(stones: &[Stone]) -> u64 {
+    count(stones, 75)
+}
+
+aoclib::examples!(Day11);
@@ -0,0 +1,196 @@
+use std::fmt::Display;
+
+use aoclib::{parse, Dir4, DisjointSet, FastMap, Result, Solution};
+
+type Vec2D = aoclib::Vec2D<i32>;
+
+#[derive(Debug, Clone, Copy)]
+struct Fence {
+    len: usize,
+    direction: Dir4,
+    x: i32,
+    y: i32,
+}
+
+impl Fence {
+    /// Creates a fence starting at `x`, `y`, which may lie outside the map.
+    fn new(x: i32, y: i32, dir: Dir4, len: usize) -> Fence {
+        Fence {
+            x,
+            y,
+            direction: dir,
+            len,
+        }
+    }
+}
+
+impl Fence {
+    /// Returns true if `self` is right of `other`.
+    fn right_of(&self, other: &Fence) -> bool {
+        other.y == self.y && other.x == self.x - other.len as i32
+    }
+
+    /// Returns true if `self` is left of `other`.
+    fn left_of(&self, other: &Fence) -> bool {
+        other.right_of(self)
+    }
+
+    /// Returns true if `self` is below `other`.
+    fn below(&self, other: &Fence) -> bool {
+        other.x == self.x && other.y == self.y - other.len as i32
+    }
+
+    /// Returns true if `self` is above `other`.
+    fn above(&self, other: &Fence) -> bool {
+        other.below(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct Region {
+    /// positions of gardens.
+    gardens: Vec<Vec2D>,
+    /// perimeter
+    fences: FastMap<Dir4, Vec<Fence>>,
+}
+
+impl Region {
+    /// Creates a new region.
+    fn new() -> Self {
+        let fence_map = FastMap::from_iter(Dir4::ALL.map(|d| (d, Vec::new())));
+        Region {
+            gardens: Vec::new(),
+            fences: fence_map,
+        }
+    }
+
+    /// Calculates the area.
+    fn area(&self) -> usize {
+        self.gardens.len()
+    }
+
+    /// Calculates the perimeter.
+    fn perimeter(&self) -> usize {
+        self.fences
+            .values()
+            .map(|fs| fs.iter().map(|f| f.len).sum::<usize>())
+            .sum()
+    }
+
+    /// Calculates the number of sides.
+    fn sides(&self) -> usize {
+        self.fences.values().map(|fs| fs.len()).sum()
+    }
+
+    /// Adds a garden to the region.
+    fn add_garden(&mut self, garden: Vec2D) {
+        self.gardens.push(garden);
+    }
+
+    /// Add a fence on the position of provided garden.
+    #[allow(non_snake_case)]
+    fn add_fence(&mut self, mut f: Fence) {
+        // look for adjacent fences with same direction.
+
+        // an adjacent fence F to f will satisfy F.x == f.x - F.len and F.y == f.y if horizonal
+        // or F.y == f.y - F.len and F.x == f.x if vertical
+        // this only applies if f is right of/below F, so we also need to check the other way around to merge the other way
+
+        let fences = self.fences.get_mut(&f.direction).unwrap();
+        // left/above the fence
+        let before;
+        // right/below the fence
+        let after;
+
+        // fences facing west or east run vertically
+        if !f.direction.is_vertical() {
+            before = fences.iter().position(|F| F.above(&f));
+            after = fences.iter().position(|F| F.below(&f));
+        } else {
+            before = fences.iter().position(|F| F.left_of(&f));
+            after = fences.iter().position(|F| F.right_of(&f));
+        }
+
+        match (before, after) {
+            // new fence
+            (None, None) => fences.push(f),
+            // expand the one before
+            (Some(b), None) => {
+                fences[b].len += f.len;
+            }
+            // expand new fence by the one after and replace
+            (None, Some(r)) => {
+                f.len += fences[r].len;
+                fences[r] = f;
+            }
+            // combination of case 2 and 3
+            (Some(l), Some(r)) => {
+                fences[l].len += f.len + fences[r].len;
+                fences.remove(r);
+            }
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Vec<Region>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // parse gardens
+        let gardens = parse::grid_chars(input)?;
+        let index = |p: Vec2D| p.y as usize * gardens.width() + p.x as usize;
+
+        // join neighbouring gardens of the same crop
+        let mut plots = DisjointSet::new(gardens.width() * gardens.height());
+        for (pos, crop) in gardens.iter() {
+            for n in [pos + Dir4::East.vec(), pos + Dir4::South.vec()] {
+                if gardens.get(n) == Some(crop) {
+                    plots.union(index(pos), index(n));
+                }
+            }
+        }
+
+        // one region per set, fenced wherever the crop changes
+        let mut regions: Vec<Region> = Vec::new();
+        let mut region_of = FastMap::default();
+        for pos in gardens.positions() {
+            let i = *region_of.entry(plots.find(index(pos))).or_insert_with(|| {
+                regions.push(Region::new());
+                regions.len() - 1
+            });
+            let region = &mut regions[i];
+            region.add_garden(pos);
+            for dir in Dir4::ALL {
+                let n = pos + dir.vec();
+                if gardens.get(n) != gardens.get(pos) {
+                    region.add_fence(Fence::new(n.x, n.y, dir, 1));
+                }
+            }
+        }
+
+        Ok(regions)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
+}
+
+#[inline]
+fn p1(regions: &[Region]) -> usize {
+    regions.iter().map(|r| r.area() * r.perimeter()).sum()
+}
+
+#[inline]
+fn p2(regions: &[Region]) -> usize {
+    regions.iter().map(|r| r.area() * r.sides()).sum()
+}
+
+aoclib::examples!(Day12);
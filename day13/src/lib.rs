@@ -0,0 +1,123 @@
+use std::{fmt::Display, ops::Add};
+
+use aoclib::{parse, Result, Solution};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Vector {
+    x: i64,
+    y: i64,
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Vector {
+    fn new(x: i64, y: i64) -> Vector {
+        Vector { x, y }
+    }
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    a: Vector,
+    b: Vector,
+    prize: Vector,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Machine>> {
+    parse::map_blocks(input, |block| {
+        let (ax, ay, bx, by, px, py) = aoclib::scan!(
+            block,
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}"
+                => i64, i64, i64, i64, i64, i64
+        )?;
+        Ok(Machine {
+            a: Vector::new(ax, ay),
+            b: Vector::new(bx, by),
+            prize: Vector::new(px, py),
+        })
+    })
+}
+
+#[inline]
+fn p1(machines: &[Machine]) -> i64 {
+    machines
+        .iter()
+        .map(|machine| {
+            let a = machine.a;
+            let b = machine.b;
+            let x = machine.prize;
+
+            let w = (x.x * a.y - x.y * a.x) / (b.x * a.y - b.y * a.x);
+            let v = (x.y - w * b.y) / a.y;
+
+            if v * a.x + w * b.x != x.x || v * a.y + w * b.y != x.y {
+                return 0;
+            }
+
+            if w >= 0 && v >= 0 && w <= 100 && v <= 100 {
+                3 * v + w
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+#[inline]
+fn p2(machines: &[Machine]) -> i64 {
+    machines
+        .iter()
+        .map(|machine| {
+            let a = machine.a;
+            let b = machine.b;
+            let x = machine.prize
+                + Vector {
+                    x: 10000000000000,
+                    y: 10000000000000,
+                };
+
+            let w = (x.x * a.y - x.y * a.x) / (b.x * a.y - b.y * a.x);
+            let v = (x.y - w * b.y) / a.y;
+
+            if v * a.x + w * b.x != x.x || v * a.y + w * b.y != x.y {
+                return 0;
+            }
+
+            if w >= 0 && v >= 0 {
+                3 * v + w
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+aoclib::examples!(Day13);
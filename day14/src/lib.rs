@@ -0,0 +1,151 @@
+use std::fmt::Display;
+
+use aoclib::{
+    math, params, parse,
+    viz::{self, Rgb},
+    Result, Solution, Vec2D,
+};
+
+/// Returns the width and height of the area, 101x103 unless the `width`
+/// and `height` parameters say otherwise (11x7 in the example).
+fn area() -> Vec2D<isize> {
+    Vec2D::new(params::get("width", 101), params::get("height", 103))
+}
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    p: Vec2D<isize>,
+    v: Vec2D<isize>,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input.clone())
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input.clone())
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Robot>> {
+    parse::map_lines(input, |l| {
+        let (px, py, vx, vy) = aoclib::scan!(l, "p={},{} v={},{}" => isize, isize, isize, isize)?;
+        Ok(Robot {
+            p: Vec2D::new(px, py),
+            v: Vec2D::new(vx, vy),
+        })
+    })
+}
+
+fn p1(mut robots: Vec<Robot>) -> usize {
+    use std::cmp::Ordering::{Equal, Greater, Less};
+    // postion of a bot after 100 seconds is p+100v mod area
+    let area = area();
+    robots.iter_mut().for_each(|robot| {
+        robot.p = (robot.p + (robot.v * 100)) % area;
+    });
+
+    let mut q1 = 0;
+    let mut q2 = 0;
+    let mut q3 = 0;
+    let mut q4 = 0;
+    for r in robots {
+        match r.p.x.cmp(&(area.x / 2)) {
+            Less => match r.p.y.cmp(&(area.y / 2)) {
+                Less => q2 += 1,
+                Greater => q3 += 1,
+                Equal => {}
+            },
+            Greater => match r.p.y.cmp(&(area.y / 2)) {
+                Less => q1 += 1,
+                Greater => q4 += 1,
+                Equal => {}
+            },
+            Equal => {}
+        }
+    }
+
+    q1 * q2 * q3 * q4
+}
+
+fn p2(mut robots: Vec<Robot>) -> isize {
+    // look at variances in X and Y,
+    // when we have a christmas tree the variance should drop dramatically
+    // because we have a huge bot cluster.
+    let area = area();
+
+    // pick the lowest variances for X
+    let mut lowest = f32::MAX;
+    let mut bx = 0;
+    for t in 1..=area.x {
+        robots
+            .iter_mut()
+            .for_each(|r| r.p.x = (r.p.x + r.v.x).rem_euclid(area.x));
+
+        // calculate the variance in X
+        let mean = robots.iter().map(|r| r.p.x as f32).sum::<f32>() / robots.len() as f32;
+        let var = robots
+            .iter()
+            .map(|r| (r.p.x as f32 - mean) * (r.p.x as f32 - mean))
+            .sum::<f32>()
+            / robots.len() as f32;
+
+        if var < lowest {
+            lowest = var;
+            bx = t;
+        }
+    }
+
+    // do the same for Y
+    let mut lowest = f32::MAX;
+    let mut by = 0;
+    for t in 1..=area.y {
+        robots
+            .iter_mut()
+            .for_each(|r| r.p.y = (r.p.y + r.v.y).rem_euclid(area.y));
+
+        // calculate the variance in Y
+        let mean = robots.iter().map(|r| r.p.y as f32).sum::<f32>() / robots.len() as f32;
+        let var = robots
+            .iter()
+            .map(|r| (r.p.y as f32 - mean) * (r.p.y as f32 - mean))
+            .sum::<f32>()
+            / robots.len() as f32;
+
+        if var < lowest {
+            lowest = var;
+            by = t;
+        }
+    }
+    aoclib::debug!("lowest variance in x at {bx}, in y at {by}");
+
+    // as we are in modular arithmetic these variance dips repeat every W and H
+    // steps, so the tree shows at t = bx (mod W) and t = by (mod H)
+    let (t, _) = math::crt(&[(bx as i64, area.x as i64), (by as i64, area.y as i64)])
+        .expect("Width and height are coprime.");
+    let t = t as isize;
+
+    if viz::enabled() {
+        // draw the tree
+        let tree = viz::points(robots.iter().map(|r| (r.p + r.v * t) % area));
+        viz::show(&tree, |&bot| match bot {
+            true => ('X', Rgb::GREEN),
+            false => ('-', Rgb::GRAY),
+        });
+        viz::save("day14.pbm", &viz::to_pbm(&tree));
+    }
+
+    t
+}
+
+aoclib::examples!(Day14);
@@ -0,0 +1,199 @@
+use std::fmt::Display;
+
+use aoclib::{
+    parse,
+    viz::{Frames, Rgb},
+    Dir4, Error, Grid, Result, Solution,
+};
+
+type Vec2D = aoclib::Vec2D<i32>;
+
+#[derive(Clone)]
+pub struct Warehouse {
+    bot: Vec2D,
+    map: Grid<char>,
+    wide: bool,
+    rules: Vec<Dir4>,
+}
+
+const OFFSET_RIGHT: Vec2D = Dir4::East.vec();
+const OFFSET_LEFT: Vec2D = Dir4::West.vec();
+
+/// Colours of the warehouse tiles in animations.
+fn color(&c: &char) -> Rgb {
+    match c {
+        '#' => Rgb::GRAY,
+        '@' => Rgb::YELLOW,
+        'O' | '[' | ']' => Rgb::BROWN,
+        _ => Rgb::BLACK,
+    }
+}
+
+impl Warehouse {
+    fn new(input: &str) -> Result<Self> {
+        let [board, rules] = parse::split_blocks(input)?;
+        Warehouse::from_board(input, board, rules, false)
+    }
+
+    fn new_wide(input: &str) -> Result<Self> {
+        let [board, rules] = parse::split_blocks(input)?;
+        // every tile is twice as wide
+        let board = board
+            .lines()
+            .map(|l| {
+                l.chars()
+                    .flat_map(|c| match c {
+                        '@' => ['@', '.'],
+                        'O' => ['[', ']'],
+                        c => [c, c],
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        Warehouse::from_board(input, &board, rules, true)
+    }
+
+    /// Splits the input into board and moves.
+    fn from_board(input: &str, board: &str, rules: &str, wide: bool) -> Result<Self> {
+        let map = Grid::try_parse_with(board, |c| "#.O[]@".contains(c).then_some(c))?;
+        let bot = map
+            .find(&'@')
+            .ok_or_else(|| Error::at(input, input, "no robot found"))?;
+        let rules = rules
+            .lines()
+            .flat_map(|l| l.char_indices().map(move |(i, c)| (&l[i..], c)))
+            .map(|(at, c)| {
+                Dir4::from_char(c).ok_or_else(|| Error::at(input, at, "unexpected move"))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Warehouse {
+            bot,
+            map,
+            wide,
+            rules,
+        })
+    }
+
+    fn gps_sum(&self) -> usize {
+        self.map
+            .iter()
+            .filter(|(_, &c)| c == 'O' || c == '[')
+            .map(|(pos, _)| 100 * pos.y as usize + pos.x as usize)
+            .sum()
+    }
+
+    fn move_seq(&mut self) {
+        let mut frames = Frames::new(if self.wide { "day15-wide" } else { "day15" }, 4);
+        let moves = self.rules.clone();
+        for (i, m) in moves.into_iter().enumerate() {
+            if self.can_move(self.bot, &m) {
+                self.mv(self.bot, &m);
+            }
+            if i % 20 == 0 {
+                frames.push(&self.map, color);
+            }
+        }
+        frames.push(&self.map, color);
+    }
+
+    fn at(&self, pos: &Vec2D) -> char {
+        self.map[*pos]
+    }
+
+    /// Recursively checks if bot and boxes can be moved.
+    fn can_move(&self, pos: Vec2D, m: &Dir4) -> bool {
+        let next = pos + m.vec();
+
+        if self.wide && m.is_vertical() {
+            match self.at(&next) {
+                '#' => false,
+                '[' => self.can_move(next, m) && self.can_move(next + OFFSET_RIGHT, m),
+                ']' => self.can_move(next + OFFSET_LEFT, m) && self.can_move(next, m),
+                _ => true,
+            }
+        } else {
+            match self.at(&next) {
+                '#' => false,
+                'O' | '[' | ']' => self.can_move(next, m),
+                _ => true,
+            }
+        }
+    }
+
+    /// Recursively moves bot and boxes from pos in m direction. If forwarded no movement occours for this object.
+    fn mv(&mut self, pos: Vec2D, m: &Dir4) {
+        let next = pos + m.vec();
+
+        if self.wide && m.is_vertical() {
+            match self.at(&next) {
+                '#' => unreachable!("Should not have encountered a wall"),
+                '[' => {
+                    self.mv(next, m);
+                    self.mv(next + OFFSET_RIGHT, m);
+                }
+                ']' => {
+                    self.mv(next + OFFSET_LEFT, m);
+                    self.mv(next, m);
+                }
+                _ => {}
+            };
+            if pos == self.bot {
+                self.bot = next;
+                self.swap(&next, &pos);
+            } else {
+                self.swap(&next, &pos);
+            }
+        } else {
+            match self.at(&next) {
+                'O' | ']' | '[' => self.mv(next, m),
+                _ => {}
+            }
+            if pos == self.bot {
+                self.bot = next;
+                self.swap(&next, &pos);
+            } else {
+                self.swap(&next, &pos);
+            }
+        }
+    }
+
+    fn swap(&mut self, p1: &Vec2D, p2: &Vec2D) {
+        self.map.swap(*p1, *p2);
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    /// The normal and the wide warehouse.
+    type Input = (Warehouse, Warehouse);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((Warehouse::new(input)?, Warehouse::new_wide(input)?))
+    }
+
+    fn part1((grid, _): &Self::Input) -> impl Display {
+        p1(grid.clone())
+    }
+
+    fn part2((_, wide_grid): &Self::Input) -> impl Display {
+        p2(wide_grid.clone())
+    }
+}
+
+#[inline]
+fn p1(mut grid: Warehouse) -> usize {
+    grid.move_seq();
+    grid.gps_sum()
+}
+
+#[inline]
+fn p2(mut grid: Warehouse) -> usize {
+    grid.move_seq();
+    grid.gps_sum()
+}
+
+aoclib::examples!(Day15);
@@ -0,0 +1,110 @@
+use std::fmt::Display;
+
+use aoclib::{
+    search::{self, Paths},
+    Dir4, FastSet, Result, Solution,
+};
+
+type Vec2D = aoclib::Vec2D<i32>;
+
+/// A position and the direction the reindeer faces.
+type State = (Vec2D, Dir4);
+
+pub struct Maze {
+    start: Vec2D,
+    end: Vec2D,
+    walls: FastSet<Vec2D>,
+}
+
+impl Maze {
+    /// Finds the cheapest paths to every state reachable from the start.
+    fn search(&self) -> Paths<State> {
+        // use dijkstra on weighted path graph
+        // edges are always every direction where the next node is not a wall
+        // cost is dependant on direction
+        search::dijkstra((self.start, Dir4::East), |&(pos, facing)| {
+            Dir4::ALL
+                .into_iter()
+                // turning a 180 will make the path longer
+                .filter(move |&dd| facing.turns_to(dd) != 2)
+                .map(move |dd| {
+                    (
+                        (pos + dd.vec(), dd),
+                        facing.turns_to(dd) as usize * 1000 + 1,
+                    )
+                })
+                .filter(|((next, _), _)| !self.walls.contains(next))
+        })
+    }
+
+    /// Returns the cost of the best path to the end, facing any direction.
+    fn best_cost(&self, paths: &Paths<State>) -> usize {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(|d| paths.cost(&(self.end, d)))
+            .min()
+            .expect("Maze is not solvable.")
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut start = Vec2D::new(1, 1);
+        let mut end = Vec2D::new(1, 1);
+        let mut walls = FastSet::default();
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        walls.insert(Vec2D::new(x as i32, y as i32));
+                    }
+                    'S' => {
+                        start = Vec2D::new(x as i32, y as i32);
+                    }
+                    'E' => {
+                        end = Vec2D::new(x as i32, y as i32);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(Maze { start, end, walls })
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
+}
+
+#[inline]
+fn p1(maze: &Maze) -> usize {
+    maze.best_cost(&maze.search())
+}
+
+#[inline]
+fn p2(maze: &Maze) -> usize {
+    let paths = maze.search();
+    let best_cost = maze.best_cost(&paths);
+    let ends = Dir4::ALL
+        .into_iter()
+        .map(|d| (maze.end, d))
+        .filter(|e| paths.cost(e) == Some(best_cost));
+    let visited: FastSet<_> = paths
+        .on_any_path(ends)
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect();
+    visited.len()
+}
+
+aoclib::examples!(Day16);
@@ -1,6 +1,8 @@
 use core::panic;
 
-use aoclib::{input, output};
+use std::fmt::Display;
+
+use aoclib::{parse, Error, Result, Solution};
 
 #[derive(Debug)]
 #[allow(dead_code)] // std::mem::transmute
@@ -65,12 +67,13 @@ impl Operand {
 }
 
 #[derive(Debug)]
-struct Instruction {
+pub struct Instruction {
     op: Op,
     v: Operand,
 }
 
-struct Computer {
+#[derive(Clone)]
+pub struct Computer {
     a: i64,
     b: i64,
     c: i64,
@@ -174,40 +177,43 @@ impl Iterator for Computer {
     }
 }
 
-fn main() {
-    let input = input("input");
-
-    let mut lines = input.lines();
-    let mut regs = [0; 3];
-    (0..3).for_each(|i| {
-        regs[i] = lines
-            .next()
-            .unwrap()
-            .split_once(":")
-            .unwrap()
-            .1
-            .trim()
-            .parse()
-            .unwrap();
-    });
-    lines.next(); // empty line
-    let program = lines.next().unwrap().split_once(":").unwrap().1.trim();
-    let rom: Vec<i8> = program.split(",").flat_map(|x| x.parse()).collect();
-
-    let mut computer = Computer::new(regs[0], regs[1], regs[2], rom);
-
-    p1(&mut computer);
-    p2(&mut computer);
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Computer;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(&mut input.clone())
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
+}
+
+fn parse(input: &str) -> Result<Computer> {
+    let [regs, program] = parse::split_blocks(input)?;
+    let [a, b, c] = parse::ints(regs)?[..] else {
+        return Err(Error::at(input, regs, "expected registers A, B and C"));
+    };
+    let rom = parse::ints(program).map_err(|e| e.within(input, program))?;
+
+    Ok(Computer::new(a, b, c, rom))
 }
 
 #[inline]
-fn p1(computer: &mut Computer) {
-    output(computer.run());
+fn p1(computer: &mut Computer) -> String {
+    computer.run()
 }
 
 #[inline]
-fn p2(computer: &mut Computer) {
-    output(find(&computer.rom, 0).expect("No solution."));
+fn p2(computer: &Computer) -> i64 {
+    find(&computer.rom, 0).expect("No solution.")
 }
 
 /// Recurse over rom checking and removing the last element and build up the answer from there.
@@ -236,3 +242,5 @@ fn find(rom: &[i8], mut answer: i64) -> Option<i64> {
     }
     None
 }
+
+aoclib::examples!(Day17);
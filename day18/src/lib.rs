@@ -0,0 +1,134 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use aoclib::{params, parse_field, BitGrid, DisjointSet, Error, Result, Solution, CARDINAL};
+
+type Vec2D = aoclib::Vec2D<i32>;
+
+const START: Vec2D = Vec2D::new(0, 0);
+
+/// Returns the largest coordinate of the memory space, 70 unless the `dim`
+/// parameter says otherwise (6 in the example).
+fn dim() -> usize {
+    params::get("dim", 70)
+}
+
+/// Returns the number of bytes fallen in part 1, 1024 unless the `bytes`
+/// parameter says otherwise (12 in the example).
+fn simulation_size() -> usize {
+    params::get("bytes", 1024)
+}
+
+/// Breadth first search through the memory space.
+struct Bfs {
+    seen: BitGrid,
+    queue: VecDeque<(Vec2D, usize)>,
+}
+
+impl Bfs {
+    fn new(corrupted: &BitGrid) -> Self {
+        Bfs {
+            seen: BitGrid::new(corrupted.width(), corrupted.height()),
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Returns the cost of the path or None if no path is found.
+    fn shortest_path(&mut self, corrupted: &BitGrid) -> Option<usize> {
+        let end = Vec2D::new(corrupted.width() as i32 - 1, corrupted.height() as i32 - 1);
+        self.seen.clear();
+        self.queue.clear();
+        self.seen.insert(START);
+        self.queue.push_back((START, 0));
+
+        while let Some((pos, cost)) = self.queue.pop_front() {
+            if pos == end {
+                return Some(cost);
+            }
+            for next in CARDINAL.map(|d| pos + d) {
+                if corrupted.in_bounds(next) && !corrupted.contains(next) && self.seen.insert(next)
+                {
+                    self.queue.push_back((next, cost + 1));
+                }
+            }
+        }
+        None
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = Vec<Vec2D>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Vec2D>> {
+    let dim = dim() as i32;
+    input
+        .lines()
+        .map(|line| {
+            let (l, r) = line
+                .split_once(",")
+                .ok_or_else(|| Error::at(input, line, "expected `x,y`"))?;
+            let p = Vec2D::new(parse_field(input, l)?, parse_field(input, r)?);
+            if !(0..=dim).contains(&p.x) || !(0..=dim).contains(&p.y) {
+                return Err(Error::at(input, line, "byte outside of memory space"));
+            }
+            Ok(p)
+        })
+        .collect()
+}
+
+/// Returns the memory space with the first `size` bytes corrupted.
+fn drop_bytes(size: usize, points: &[Vec2D]) -> BitGrid {
+    let dim = dim();
+    let mut grid = BitGrid::new(dim + 1, dim + 1);
+    points.iter().take(size).for_each(|&p| {
+        grid.insert(p);
+    });
+    grid
+}
+
+#[inline]
+fn p1(points: &[Vec2D]) -> usize {
+    let grid = drop_bytes(simulation_size(), points);
+    Bfs::new(&grid)
+        .shortest_path(&grid)
+        .expect("No path found.")
+}
+
+#[inline]
+fn p2(points: &[Vec2D]) -> String {
+    let side = dim() + 1;
+    let index = |p: Vec2D| p.y as usize * side + p.x as usize;
+    let neighbours = |i: usize| {
+        let pos = Vec2D::new((i % side) as i32, (i / side) as i32);
+        CARDINAL
+            .map(|d| pos + d)
+            .into_iter()
+            .filter(|n| (0..side as i32).contains(&n.x) && (0..side as i32).contains(&n.y))
+            .map(index)
+    };
+    let bytes: Vec<usize> = points.iter().map(|&p| index(p)).collect();
+    let end = side * side - 1;
+
+    // fill in all bytes, then take them out again from the last one on
+    let byte = DisjointSet::first_separating(side * side, &bytes, neighbours, index(START), end)
+        .map(|i| points[i])
+        .expect("Exit never gets blocked.");
+    format!("{},{}", byte.x, byte.y)
+}
+
+aoclib::examples!(Day18);
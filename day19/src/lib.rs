@@ -0,0 +1,81 @@
+use std::fmt::Display;
+
+use aoclib::{memo::Memo, parse, Result, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    /// Available patterns and the desired towels.
+    type Input = (Vec<String>, Vec<String>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let [patterns, towels] = parse::split_blocks(input)?;
+        let patterns = patterns.split(", ").map(String::from).collect();
+        let towels = towels.lines().map(String::from).collect();
+
+        Ok((patterns, towels))
+    }
+
+    fn part1((patterns, towels): &Self::Input) -> impl Display {
+        p1(towels, patterns)
+    }
+
+    fn part2((patterns, towels): &Self::Input) -> impl Display {
+        p2(towels, patterns)
+    }
+}
+
+/// Greedily search for a solution.
+fn backtrack(towel: &str, patterns: &[String], memo: &mut Memo<String, bool>) -> bool {
+    if towel.is_empty() {
+        return true;
+    }
+
+    memo.get_or_compute(towel, |memo| {
+        patterns.iter().any(|pattern| {
+            towel
+                .strip_prefix(pattern.as_str())
+                .is_some_and(|rest| backtrack(rest, patterns, memo))
+        })
+    })
+}
+
+/// Searches all solutions, slower.
+fn backtrack_count(towel: &str, patterns: &[String], memo: &mut Memo<String, u64>) -> u64 {
+    if towel.is_empty() {
+        return 1;
+    }
+
+    memo.get_or_compute(towel, |memo| {
+        patterns
+            .iter()
+            .filter_map(|pattern| towel.strip_prefix(pattern.as_str()))
+            .map(|rest| backtrack_count(rest, patterns, memo))
+            .sum()
+    })
+}
+
+#[inline]
+fn p1(towels: &[String], patterns: &[String]) -> usize {
+    let mut memo = Memo::new();
+    let possible = towels
+        .iter()
+        .filter(|t| backtrack(t, patterns, &mut memo))
+        .count();
+    aoclib::debug!("towel cache: {}", memo.stats());
+    possible
+}
+
+#[inline]
+fn p2(towels: &[String], patterns: &[String]) -> u64 {
+    let mut memo = Memo::new();
+    let all: u64 = towels
+        .iter()
+        .map(|t| backtrack_count(t, patterns, &mut memo))
+        .sum();
+    aoclib::debug!("towel cache: {}", memo.stats());
+    all
+}
+
+aoclib::examples!(Day19);
@@ -1,35 +1,51 @@
-use aoclib::{input, output};
+use std::fmt::Display;
+
+use aoclib::{parse_field, Result, Solution};
 
 type Report = Vec<i32>;
 type ReportVec = Vec<Report>;
 
-fn main() {
-    let input = input("input");
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = ReportVec;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
 
-    let reports = input
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
+}
+
+fn parse(input: &str) -> Result<ReportVec> {
+    input
         .lines()
         .map(|l| {
             l.split_whitespace()
-                .map(|x| x.parse::<i32>().unwrap())
+                .map(|x| parse_field(input, x))
                 .collect()
         })
-        .collect();
-
-    p1(&reports);
-    p2(&reports);
+        .collect()
 }
 
 /// Returns number of unsafe reports.
 #[inline]
-fn p1(reports: &ReportVec) {
+fn p1(reports: &ReportVec) -> usize {
     let safe = reports.iter().filter(|r| is_safe(r)).count();
-    output(safe);
+    safe
 }
 
 #[inline]
-fn p2(reports: &ReportVec) {
+fn p2(reports: &ReportVec) -> usize {
     let safe = reports.iter().filter(|r| is_semi_safe(r)).count();
-    output(safe);
+    safe
 }
 
 fn is_semi_safe(report: &Report) -> bool {
@@ -56,3 +72,5 @@ fn is_safe(report: &Report) -> bool {
     diffs.iter().all(|diff| (1..=3).contains(diff))
         || diffs.iter().all(|diff| (-3..=-1).contains(diff))
 }
+
+aoclib::examples!(Day2);
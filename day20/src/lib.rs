@@ -0,0 +1,124 @@
+use std::fmt::Display;
+
+use aoclib::{params, search, FastMap, FastSet, Result, Solution, CARDINAL};
+
+type Vec2D = aoclib::Vec2D<i32>;
+
+/// Returns how many picoseconds a cheat has to save at least, 100 unless the
+/// `min_save` parameter says otherwise.
+fn min_save() -> usize {
+    params::get("min_save", 100)
+}
+
+pub struct Grid {
+    start: Vec2D,
+    end: Vec2D,
+    walls: FastSet<Vec2D>,
+}
+
+impl Grid {
+    fn new(start: Vec2D, end: Vec2D, walls: FastSet<Vec2D>) -> Grid {
+        Grid { start, end, walls }
+    }
+
+    /// Computes the by definition unique path between start and end.
+    fn shortest_path(&self) -> Vec<Vec2D> {
+        // WE WILL NEVER CHEAT. We want to find the intended route.
+        search::bfs(self.start, |&pos| {
+            CARDINAL
+                .into_iter()
+                .map(move |d| pos + d)
+                .filter(|next| !self.walls.contains(next))
+        })
+        .path_to(&self.end)
+        .unwrap_or_default()
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut start = Vec2D::new(0, 0);
+        let mut end = Vec2D::new(1, 1);
+        let mut walls = FastSet::default();
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        walls.insert(Vec2D::new(x as i32, y as i32));
+                    }
+                    'S' => start = Vec2D::new(x as i32, y as i32),
+                    'E' => end = Vec2D::new(x as i32, y as i32),
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(Grid::new(start, end, walls))
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
+}
+
+/// Returns the number of cheat paths that save `timesave` or more time.
+fn count_2ps_cheat_paths(path: &[Vec2D], timesave: usize) -> usize {
+    let times: FastMap<Vec2D, usize> = path.iter().enumerate().map(|(x, &y)| (y, x)).collect();
+    let mut count = 0;
+    // for every point check every cheat
+    for &p in path {
+        // O(n)
+        let t = times[&p];
+        // check step 1 in all cardinal directions
+        for d in CARDINAL {
+            let np = p + d;
+            // now check every follow-up-step
+            for d2 in CARDINAL {
+                let nnp = np + d2;
+                if let Some(&nnt) = times.get(&nnp) {
+                    if nnt >= t + 2 && nnt - (t + 2) >= timesave {
+                        count += 1;
+                    }
+                }
+            }
+        }
+    }
+    count
+}
+
+#[inline]
+fn p1(grid: &Grid) -> usize {
+    count_2ps_cheat_paths(&grid.shortest_path(), min_save())
+}
+
+#[inline]
+fn p2(grid: &Grid) -> usize {
+    let path = grid.shortest_path();
+    let min_save = min_save();
+    let mut count = 0;
+    let intended_length = path.len();
+    // for every pair a cheat needs to have l1 distance <= 20
+    for (i, &p) in path.iter().enumerate() {
+        for (j, &other) in path.iter().enumerate().skip(i + 1) {
+            // way left (subtract start and intermediate path)
+            let way_to_go = intended_length - j;
+            let traveled = p.manhattan(other);
+            if traveled <= 20 && intended_length - way_to_go - traveled as usize - i >= min_save {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+aoclib::examples!(Day20);
@@ -0,0 +1,91 @@
+use std::fmt::Display;
+
+use aoclib::{math::ModInt, par, FastMap, FastSet, Result, Solution};
+
+/// Secret numbers are pruned to 24 bits after every step.
+type Secret = ModInt<16777216>;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Input = Vec<Secret>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .lines()
+            .flat_map(|l| l.parse::<u64>())
+            .map(Secret::from)
+            .collect())
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
+}
+
+/// Returns the secret number following `s`.
+fn next(s: Secret) -> Secret {
+    // step 1
+    let s = mix(s, s * Secret::from(64u64));
+    // step 2
+    let s = mix(s, Secret::from(s.value() / 32));
+    // step 3
+    mix(s, s * Secret::from(2048u64))
+}
+
+/// Mixes `value` into the secret `s`. Pruning a xor of two pruned numbers
+/// changes nothing, the modulus being a power of two.
+fn mix(s: Secret, value: Secret) -> Secret {
+    Secret::from(s.value() ^ value.value())
+}
+
+/// Returns the price of the secret `s`, its last digit.
+fn price(s: Secret) -> i64 {
+    (s.value() % 10) as i64
+}
+
+#[inline]
+fn p1(init: &[Secret]) -> u64 {
+    par::par_sum(init, |&s| (0..2000).fold(s, |s, _| next(s)).value())
+}
+
+#[inline]
+fn p2(init: &[Secret]) -> i64 {
+    // every buyer sells at the first occurence of a 4 diff seq pattern
+    let firsts = par::par_map(init, |&s| {
+        let mut prices = Vec::with_capacity(2001);
+        prices.push(price(s));
+        let mut s = s;
+        for _ in 0..2000 {
+            s = next(s);
+            prices.push(price(s));
+        }
+
+        let diffs: Vec<i64> = prices.windows(2).map(|w| w[1] - w[0]).collect();
+        let mut seen = FastSet::default();
+        let mut firsts = Vec::new();
+        for i in 0..diffs.len() - 4 {
+            // 4 diff seq
+            let seq: [i64; 4] = diffs[i..i + 4].try_into().unwrap();
+            if seen.insert(seq) {
+                firsts.push((seq, prices[i + 4]));
+            }
+        }
+        firsts
+    });
+
+    // map seq pattern to accumulated price
+    let mut map = FastMap::default();
+    for (seq, price) in firsts.into_iter().flatten() {
+        *map.entry(seq).or_insert(0) += price;
+    }
+
+    *map.values().max().unwrap()
+}
+
+aoclib::examples!(Day22);
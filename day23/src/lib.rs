@@ -0,0 +1,60 @@
+use std::fmt::Display;
+
+use aoclib::{graph::Graph, parse, Error, Result, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    /// The network of computers.
+    type Input = Graph<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // build a graph
+        let mut graph = Graph::undirected();
+        parse::map_lines(input, |c| {
+            let (l, r) = c
+                .split_once("-")
+                .ok_or_else(|| Error::at(c, c, "expected connection `a-b`"))?;
+            graph.add_edge(l, r);
+            Ok(())
+        })?;
+        Ok(graph)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
+}
+
+#[inline]
+fn p1(graph: &Graph<String>) -> usize {
+    // count the triangles with the chief historians computer
+    graph
+        .triangles()
+        .into_iter()
+        .filter(|t| t.iter().any(|&c| graph.label(c).starts_with('t')))
+        .count()
+}
+
+#[inline]
+fn p2(graph: &Graph<String>) -> String {
+    let max = graph
+        .maximal_cliques()
+        .into_iter()
+        .max_by_key(Vec::len)
+        .unwrap_or_default();
+    let mut out = max
+        .iter()
+        .map(|&idx| graph.label(idx).as_str())
+        .collect::<Vec<_>>();
+    // sort alphabetically
+    out.sort();
+    out.join(",")
+}
+
+aoclib::examples!(Day23);
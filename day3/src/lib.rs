@@ -1,7 +1,9 @@
-use aoclib::{input, output};
+use std::fmt::Display;
+
+use aoclib::{parse_field, Result, Solution};
 
 #[derive(Debug, Clone)]
-struct Mul {
+pub struct Mul {
     pub lhs: i32,
     pub rhs: i32,
     pub enabled: bool,
@@ -31,11 +33,28 @@ enum ParserState {
     FindNum(u8),
 }
 
-fn main() {
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Vec<Mul>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Mul>> {
     use ParserState::*;
-    let input = input("input");
 
-    // parse the input
     let mut state = FindToken;
     let mut current_mul = Mul::new(0, 0);
     let mut i = 0;
@@ -65,26 +84,23 @@ fn main() {
             }
             FindNum(end_char) => {
                 // match number characters until ','
-                let mut num: Vec<u8> = Vec::new();
+                let start = i;
                 let mut ends_with_char = false;
                 while i < input.len() {
                     let c = input.as_bytes()[i];
-                    if c.is_ascii_digit() {
-                        num.push(c);
-                    } else {
-                        if c == end_char {
-                            ends_with_char = true;
-                        }
+                    if !c.is_ascii_digit() {
+                        ends_with_char = c == end_char;
                         break;
                     }
                     i += 1;
                 }
+                let num = &input[start..i];
                 if ends_with_char && !num.is_empty() {
                     if end_char == b',' {
-                        current_mul.lhs = String::from_utf8(num).unwrap().parse::<i32>().unwrap();
+                        current_mul.lhs = parse_field(input, num)?;
                         state = FindNum(b')');
                     } else {
-                        current_mul.rhs = String::from_utf8(num).unwrap().parse::<i32>().unwrap();
+                        current_mul.rhs = parse_field(input, num)?;
                         current_mul.enabled = do_muls;
                         muls.push(current_mul.clone());
                         state = FindToken;
@@ -97,20 +113,17 @@ fn main() {
         i += 1;
     }
 
-    p1(&muls);
-    p2(&muls);
+    Ok(muls)
 }
 
 #[inline]
-fn p1(muls: &[Mul]) {
-    let result: i32 = muls.iter().map(|m| m.calc(true)).sum();
-
-    output(result);
+fn p1(muls: &[Mul]) -> i32 {
+    muls.iter().map(|m| m.calc(true)).sum()
 }
 
 #[inline]
-fn p2(muls: &[Mul]) {
-    let result: i32 = muls.iter().map(|m| m.calc(false)).sum();
-
-    output(result);
+fn p2(muls: &[Mul]) -> i32 {
+    muls.iter().map(|m| m.calc(false)).sum()
 }
+
+aoclib::examples!(Day3);
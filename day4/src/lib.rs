@@ -1,56 +1,30 @@
-use std::slice::Iter;
+use std::fmt::Display;
 
-use aoclib::{input, output};
+use aoclib::{Dir8, Result, Solution};
 
-fn main() {
-    let input = input("example2");
+pub struct Day4;
 
-    let vec: Vec<&[u8]> = input.lines().map(|l| l.as_bytes()).collect();
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<Vec<u8>>;
 
-    p1(&vec);
-    p2(&vec);
-}
-
-#[derive(Debug)]
-enum Direction {
-    North,
-    NorthWest,
-    West,
-    SouthWest,
-    South,
-    SouthEast,
-    East,
-    NorthEast,
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(|l| l.as_bytes().to_vec()).collect())
+    }
 
-impl Direction {
-    /// Returns (m * dy, m * dx).
-    fn d(&self, m: i32) -> (i32, i32) {
-        match self {
-            Direction::North => (-m, 0),
-            Direction::NorthWest => (-m, -m),
-            Direction::West => (0, -m),
-            Direction::SouthWest => (m, -m),
-            Direction::South => (m, 0),
-            Direction::SouthEast => (m, m),
-            Direction::East => (0, m),
-            Direction::NorthEast => (-m, m),
-        }
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
     }
 
-    fn iter() -> Iter<'static, Direction> {
-        use Direction::*;
-        static DIRECTIONS: [Direction; 8] = [
-            North, NorthWest, West, SouthWest, South, SouthEast, East, NorthEast,
-        ];
-        DIRECTIONS.iter()
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
     }
 }
 
 const XMAS: [u8; 4] = [b'X', b'M', b'A', b'S'];
 
 #[inline]
-fn p1(input: &[&[u8]]) {
+fn p1(input: &[Vec<u8>]) -> i32 {
     let height = input.len() as i32;
     let width = input[0].len() as i32;
 
@@ -59,10 +33,10 @@ fn p1(input: &[&[u8]]) {
     for y in 0..height {
         for x in 0..width {
             // look for xmas in every direction
-            'dirl: for dir in Direction::iter() {
+            'dirl: for dir in Dir8::ALL {
                 for i in 0..4 {
-                    let v = dir.d(i);
-                    let (y, x) = (y + v.0, x + v.1);
+                    let v = dir.vec() * i;
+                    let (y, x) = (y + v.y, x + v.x);
                     if y >= height || y < 0 || x >= width || x < 0 {
                         continue 'dirl;
                     }
@@ -76,11 +50,11 @@ fn p1(input: &[&[u8]]) {
         }
     }
 
-    output(count);
+    count
 }
 
 #[inline]
-fn p2(input: &[&[u8]]) {
+fn p2(input: &[Vec<u8>]) -> i32 {
     let height = input.len();
     let width = input[0].len();
 
@@ -112,5 +86,7 @@ fn p2(input: &[&[u8]]) {
         }
     }
 
-    output(count);
+    count
 }
+
+aoclib::examples!(Day4);
@@ -0,0 +1,97 @@
+use std::fmt::Display;
+
+use aoclib::{graph::Graph, parse, parse_field, Result, Solution};
+
+/// Page ordering rules, with an edge from every page to the pages that have
+/// to come after it.
+pub type Rules = Graph<i32>;
+
+#[derive(Debug)]
+pub struct Update {
+    /// The pages as nodes of the rules.
+    pub pages: Vec<usize>,
+    pub correct: bool,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = (Rules, Vec<Update>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1((rules, updates): &Self::Input) -> impl Display {
+        p1(rules, updates)
+    }
+
+    fn part2((rules, updates): &Self::Input) -> impl Display {
+        p2(rules, updates)
+    }
+}
+
+fn parse(input: &str) -> Result<(Rules, Vec<Update>)> {
+    let [rules, updates] = parse::split_blocks(input)?;
+
+    let mut graph = Graph::directed();
+    parse::map_lines(rules, |l| {
+        let (lower, upper) = aoclib::scan!(l, "{}|{}" => i32, i32)?;
+        graph.add_edge(&lower, &upper);
+        Ok(())
+    })
+    .map_err(|e| e.within(input, rules))?;
+    let updates = parse::map_lines(updates, |l| {
+        let pages = l
+            .split(",")
+            .map(|x| Ok(graph.node(&parse_field(l, x)?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Update {
+            correct: is_ordered(&graph, &pages),
+            pages,
+        })
+    })
+    .map_err(|e| e.within(input, updates))?;
+
+    Ok((graph, updates))
+}
+
+/// Returns true if no page has to come before one in front of it.
+fn is_ordered(rules: &Rules, pages: &[usize]) -> bool {
+    pages
+        .iter()
+        .enumerate()
+        .all(|(i, &x)| pages[..i].iter().all(|&before| !rules.has_edge(x, before)))
+}
+
+/// Returns the value of the middle page.
+fn middle(rules: &Rules, pages: &[usize]) -> i32 {
+    *rules.label(pages[pages.len() / 2])
+}
+
+#[inline]
+fn p1(rules: &Rules, updates: &[Update]) -> i32 {
+    updates
+        .iter()
+        .filter(|u| u.correct)
+        .map(|u| middle(rules, &u.pages))
+        .sum()
+}
+
+#[inline]
+fn p2(rules: &Rules, updates: &[Update]) -> i32 {
+    updates
+        .iter()
+        .filter(|u| !u.correct)
+        .map(|u| {
+            // the rules are cyclic as a whole, but not within an update
+            let sorted = rules
+                .topo_sort_of(&u.pages)
+                .expect("Rules of an update are not cyclic.");
+            middle(rules, &sorted)
+        })
+        .sum()
+}
+
+aoclib::examples!(Day5);
@@ -0,0 +1,153 @@
+use std::fmt::Display;
+
+use aoclib::{
+    par,
+    viz::{self, Rgb},
+    BitGrid, Dir4, Error, Grid, Result, Solution, Vec2D,
+};
+
+type Position = Vec2D<i32>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pos: Position,
+    direction: Dir4,
+}
+
+fn obstacle(grid: &Grid<char>, pos: Position) -> bool {
+    grid.get(pos) == Some(&'#')
+}
+
+impl Guard {
+    /// Steps the guard.
+    fn step(&mut self, grid: &Grid<char>) -> bool {
+        while self.facing_obstacle(grid) {
+            self.direction = self.direction.turn_right();
+        }
+        let new = self.pos + self.direction.vec();
+        if grid.in_bounds(new) {
+            self.pos = new;
+            true
+        } else {
+            // guard left the scence
+            false
+        }
+    }
+
+    fn facing_obstacle(&self, grid: &Grid<char>) -> bool {
+        let front = self.pos + self.direction.vec();
+        obstacle(grid, front)
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = (Grid<char>, Guard);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1((grid, guard): &Self::Input) -> impl Display {
+        p1(grid, *guard)
+    }
+
+    fn part2((grid, guard): &Self::Input) -> impl Display {
+        p2(grid, *guard)
+    }
+}
+
+fn parse(input: &str) -> Result<(Grid<char>, Guard)> {
+    let grid = Grid::try_parse_with(input, |c| {
+        matches!(c, '.' | '#' | '^' | '<' | 'v' | '>').then_some(c)
+    })?;
+    let guard = grid
+        .iter()
+        .find_map(|(pos, &c)| Some((pos, Dir4::from_char(c)?)))
+        .map(|(pos, direction)| Guard { pos, direction })
+        .ok_or_else(|| Error::at(input, input, "no guard found"))?;
+
+    Ok((grid, guard))
+}
+
+#[inline]
+fn p1(grid: &Grid<char>, mut guard: Guard) -> usize {
+    let mut grid = grid.clone();
+    while guard.step(&grid) {
+        grid[guard.pos] = 'X';
+    }
+    if viz::enabled() {
+        // the patrol route
+        viz::show(&grid, |&c| match c {
+            '#' => ('#', Rgb::GRAY),
+            'X' => ('X', Rgb::YELLOW),
+            c => (c, Rgb::BLUE),
+        });
+        let image = viz::to_ppm(&grid, 4, |&c| match c {
+            '#' => Rgb::GRAY,
+            'X' => Rgb::YELLOW,
+            _ => Rgb::BLACK,
+        });
+        viz::save("day6.ppm", &image);
+    }
+
+    // count
+    grid.iter().filter(|(_, &c)| c == 'X').count()
+}
+
+#[inline]
+fn p2(grid: &Grid<char>, guard: Guard) -> usize {
+    // there is probably a better algorithm but we'll just use brute force,
+    // one column per task
+    par::par_sum(0..grid.width(), |x| {
+        let mut g = grid.clone();
+        let mut seen = BitGrid::with_layers(grid.width(), grid.height(), 4);
+        (0..grid.height())
+            .filter(|&y| loops_at(x, y, &mut g, &mut seen, &guard))
+            .count()
+    })
+}
+
+/// Checks whether the guard loops if obstacle at (x,y).
+fn loops_at(x: usize, y: usize, grid: &mut Grid<char>, seen: &mut BitGrid, guard: &Guard) -> bool {
+    let p = Position::new(x as i32, y as i32);
+
+    // can't place on existing or guard
+    if guard.pos == p || obstacle(grid, p) {
+        return false;
+    }
+
+    // place and run
+    let prev = grid[p];
+    grid[p] = '#';
+    let looping = loops(grid, *guard, seen);
+
+    // reset grid
+    grid[p] = prev;
+
+    looping
+}
+
+/// Checks whether the guard loops, using `seen` for the visited states.
+fn loops(grid: &Grid<char>, mut guard: Guard, seen: &mut BitGrid) -> bool {
+    seen.clear();
+    seen.insert_in(guard.pos, guard.direction as usize);
+
+    loop {
+        while guard.facing_obstacle(grid) {
+            guard.direction = guard.direction.turn_right();
+        }
+        guard.pos += guard.direction.vec();
+        if !grid.in_bounds(guard.pos) {
+            return false;
+        }
+        if !seen.insert_in(guard.pos, guard.direction as usize) {
+            // looping
+            return true;
+        }
+    }
+}
+
+aoclib::examples!(Day6);
@@ -1,45 +1,53 @@
-use aoclib::{input, output};
+use std::fmt::Display;
+
+use aoclib::{par, parse_field, Error, Result, Solution};
 
 #[derive(Debug)]
-struct Expr {
+pub struct Expr {
     result: u64,
     operands: Vec<u64>,
 }
 
-impl From<&str> for Expr {
-    fn from(value: &str) -> Self {
-        let (res, r) = value.split_once(":").unwrap();
+impl Expr {
+    /// Parses `line`, a line of `input`.
+    fn parse(input: &str, line: &str) -> Result<Self> {
+        let (res, r) = line
+            .split_once(":")
+            .ok_or_else(|| Error::at(input, line, "expected `result: operands`"))?;
         let operands = r
             .split_whitespace()
-            .map(|x| x.parse::<u64>().unwrap())
-            .collect();
+            .map(|x| parse_field(input, x))
+            .collect::<Result<_>>()?;
 
-        Expr {
-            result: res.parse::<u64>().unwrap(),
+        Ok(Expr {
+            result: parse_field(input, res)?,
             operands,
-        }
+        })
     }
 }
 
-fn main() {
-    let input = input("input");
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Vec<Expr>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(|l| Expr::parse(input, l)).collect()
+    }
 
-    // fill expression list
-    let exprs: Vec<Expr> = input.lines().map(Expr::from).collect();
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
 
-    p1(&exprs);
-    p2(&exprs);
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
 }
 
 #[inline]
-fn p1(exprs: &[Expr]) {
-    output(
-        exprs
-            .iter()
-            .filter(|expr| is_possible_2ops(expr))
-            .map(|e| e.result)
-            .sum::<u64>(),
-    );
+fn p1(exprs: &[Expr]) -> u64 {
+    par::par_sum(exprs, |e| if is_possible_2ops(e) { e.result } else { 0 })
 }
 
 /// Returns true of the expression can be calculated using (+) and (*).
@@ -70,14 +78,8 @@ fn is_possible_2ops(expr: &Expr) -> bool {
 }
 
 #[inline]
-fn p2(exprs: &[Expr]) {
-    output(
-        exprs
-            .iter()
-            .filter(|e| is_possible_3ops(e))
-            .map(|e| e.result)
-            .sum::<u64>(),
-    );
+fn p2(exprs: &[Expr]) -> u64 {
+    par::par_sum(exprs, |e| if is_possible_3ops(e) { e.result } else { 0 })
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -141,3 +143,5 @@ fn is_possible_3ops(expr: &Expr) -> bool {
         }
     }
 }
+
+aoclib::examples!(Day7);
@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use aoclib::{parse, Result, Solution, SparseGrid};
+
+type Vec2D = aoclib::Vec2D<i32>;
+
+/// The antennas by position and the size of the map.
+#[derive(Debug)]
+pub struct Map {
+    antennas: SparseGrid<char>,
+    width: i32,
+    height: i32,
+}
+
+impl Map {
+    fn contains(&self, pos: Vec2D) -> bool {
+        (0..self.width).contains(&pos.x) && (0..self.height).contains(&pos.y)
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = parse::grid_chars(input)?;
+        let mut antennas = SparseGrid::new('.');
+        for (pos, &freq) in grid.iter().filter(|(_, &c)| c != '.') {
+            antennas.set(pos, freq);
+        }
+
+        Ok(Map {
+            antennas,
+            width: grid.width() as i32,
+            height: grid.height() as i32,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
+}
+
+fn calc_nodes(map: &Map, resharm: bool) -> usize {
+    let antennas: Vec<(Vec2D, char)> = map.antennas.iter().map(|(p, &f)| (p, f)).collect();
+    let mut antinodes = SparseGrid::new(false);
+    for (i, &(p1, f1)) in antennas.iter().enumerate() {
+        for &(p2, f2) in antennas[i + 1..].iter() {
+            if f1 != f2 {
+                continue;
+            }
+
+            // t1 and t2 are on a line y=mx+b,
+            // so we can just step forward and backwards with differences
+            let d = p1 - p2;
+            for (mut p, step) in [(p1, d), (p2, -d)] {
+                while map.contains(p + step) {
+                    p += step;
+                    antinodes.set(p, true);
+                    // part 2 keeps going until leaving the map
+                    if !resharm {
+                        break;
+                    }
+                }
+            }
+
+            if resharm {
+                antinodes.set(p1, true);
+                antinodes.set(p2, true);
+            }
+        }
+    }
+
+    // the map around the antinodes
+    aoclib::debug!(
+        "antinodes:\n{}",
+        antinodes.render(|&b| if b { '#' } else { '.' }).trim_end()
+    );
+    antinodes.len()
+}
+
+#[inline]
+fn p1(map: &Map) -> usize {
+    calc_nodes(map, false)
+}
+
+#[inline]
+fn p2(map: &Map) -> usize {
+    calc_nodes(map, true)
+}
+
+aoclib::examples!(Day8);
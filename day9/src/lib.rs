@@ -1,19 +1,30 @@
-use aoclib::{input, output};
+use std::fmt::Display;
+
+use aoclib::{Result, Solution};
 
 type FileID = usize;
 type BlockSize = usize;
 
-fn main() {
-    let input = input("input");
+pub struct Day9;
 
-    let dense: Vec<BlockSize> = input
-        .chars()
-        .flat_map(|x| x.to_string().parse::<BlockSize>())
-        .collect();
-    let blocks = expand(&dense);
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<BlockSize>;
 
-    p1(&blocks);
-    p2(&dense);
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .chars()
+            .flat_map(|x| x.to_string().parse::<BlockSize>())
+            .collect())
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
 }
 
 fn expand(dense: &[BlockSize]) -> Vec<Option<FileID>> {
@@ -34,7 +45,9 @@ fn expand(dense: &[BlockSize]) -> Vec<Option<FileID>> {
 }
 
 #[inline]
-fn p1(blocks: &[Option<FileID>]) {
+fn p1(dense: &[BlockSize]) -> usize {
+    let blocks = expand(dense);
+
     let mut r = blocks.len() - 1;
     let mut l = 0usize;
 
@@ -58,7 +71,7 @@ fn p1(blocks: &[Option<FileID>]) {
         l += 1;
     }
 
-    output(chsum);
+    chsum
 }
 
 #[derive(Debug)]
@@ -102,7 +115,7 @@ impl MemNode {
 }
 
 #[inline]
-fn p2(dense: &[BlockSize]) {
+fn p2(dense: &[BlockSize]) -> usize {
     let mut start = 0;
     let mut file_id = 0;
     let mut memory: Vec<MemNode> = dense
@@ -186,5 +199,7 @@ fn p2(dense: &[BlockSize]) {
         r -= 1;
     }
 
-    output(memory.iter().map(|node| node.chsum()).sum::<usize>());
+    memory.iter().map(|node| node.chsum()).sum()
 }
+
+aoclib::examples!(Day9);
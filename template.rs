@@ -1,24 +1,42 @@
-use aoclib::{input, output};
+use std::fmt::Display;
 
-fn main() {
-    let input = input("input");
+use aoclib::{Result, Solution};
 
-    // Data preprocessing code goes here
+pub struct DayXX;
 
-    p1();
-    p2();
+impl Solution for DayXX {
+    const DAY: u8 = XX;
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // Data preprocessing code goes here
+        // use aoclib::parse_field and aoclib::Error::at to report malformed input
+
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        p2(input)
+    }
 }
 
 #[inline]
-fn p1() {
+fn p1(_input: &[String]) -> i64 {
     // part 1 goes here
 
-    // output(result);
+    0
 }
 
 #[inline]
-fn p2() {
+fn p2(_input: &[String]) -> i64 {
     // part 2 goes here
 
-    // output(result);
+    0
 }
+
+// tests the solution against every example file annotated in examples.toml
+aoclib::examples!(DayXX);